@@ -0,0 +1,725 @@
+//! Runtime loading of libnvidia-ml.
+//!
+//! Instead of linking against `libnvidia-ml` at build time, [`NvmlLib`] opens the
+//! shared library with `dlopen` and resolves every `nvml*` entry point listed below
+//! into a function table. Binaries built on top of it start on hosts without the
+//! NVIDIA driver and only fail once NVML is actually needed.
+//!
+//! Functions that the installed driver does not export are not fatal: calling them
+//! returns `NVML_ERROR_FUNCTION_NOT_FOUND`, as NVML itself does for entry points
+//! unknown to an older library.
+
+use std::fmt;
+use std::os::raw::{c_char, c_int, c_uint, c_ulong, c_ulonglong};
+
+use libloading::Library;
+
+use crate::bindings::*;
+
+/// File names tried, in order, when opening the library.
+#[cfg(not(windows))]
+pub const LIBRARY_NAMES: &[&str] = &["libnvidia-ml.so.1", "libnvidia-ml.so"];
+/// File names tried, in order, when opening the library.
+#[cfg(windows)]
+pub const LIBRARY_NAMES: &[&str] = &["nvml.dll"];
+
+/// The reasons loading libnvidia-ml can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    /// None of the candidate file names could be opened.
+    LibraryNotFound { names: Vec<String>, reason: String },
+    /// The library was opened but lacks a symbol NVML cannot work without.
+    SymbolMissing { symbol: &'static str },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::LibraryNotFound { names, reason } => write!(
+                f,
+                "unable to load NVML library (tried {}): {}",
+                names.join(", "),
+                reason
+            ),
+            LoadError::SymbolMissing { symbol } => {
+                write!(f, "NVML library does not export required symbol {}", symbol)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+type ErrorStringFn = unsafe extern "C" fn(nvmlReturn_t) -> *const c_char;
+
+macro_rules! nvml_library {
+    ($(fn $name:ident($($arg:ident: $ty:ty),* $(,)?);)*) => {
+        /// Function table of a dynamically loaded libnvidia-ml.
+        ///
+        /// Every [`Backend`] method mirrors the function of the same name in
+        /// `include/nvml.h`.
+        pub struct NvmlLib {
+            nvmlErrorString: ErrorStringFn,
+            $($name: Option<unsafe extern "C" fn($($ty),*) -> nvmlReturn_t>,)*
+            // Keep the library mapped for as long as the pointers above are alive.
+            _library: Library,
+        }
+
+        // The safety contract of every method is the one of the C function it forwards to.
+        #[allow(clippy::missing_safety_doc)]
+        impl NvmlLib {
+            unsafe fn resolve(library: Library) -> Result<NvmlLib, LoadError> {
+                let nvmlErrorString = *library
+                    .get::<ErrorStringFn>(b"nvmlErrorString\0")
+                    .map_err(|_| LoadError::SymbolMissing { symbol: "nvmlErrorString" })?;
+                let lib = NvmlLib {
+                    nvmlErrorString,
+                    $($name: library
+                        .get(concat!(stringify!($name), "\0").as_bytes())
+                        .ok()
+                        .map(|symbol| *symbol),)*
+                    _library: library,
+                };
+                for (symbol, present) in &[
+                    ("nvmlInit_v2", lib.nvmlInit_v2.is_some()),
+                    ("nvmlShutdown", lib.nvmlShutdown.is_some()),
+                ] {
+                    if !present {
+                        return Err(LoadError::SymbolMissing { symbol: *symbol });
+                    }
+                }
+                Ok(lib)
+            }
+
+            /// Returns whether the loaded library exports `symbol`.
+            pub fn has_symbol(&self, symbol: &str) -> bool {
+                match symbol {
+                    "nvmlErrorString" => true,
+                    $(stringify!($name) => self.$name.is_some(),)*
+                    _ => false,
+                }
+            }
+
+            pub unsafe fn nvmlErrorString(&self, result: nvmlReturn_t) -> *const c_char {
+                (self.nvmlErrorString)(result)
+            }
+        }
+
+        /// The NVML entry points the safe wrappers are built on.
+        ///
+        /// [`NvmlLib`] forwards every method to the loaded library. Other
+        /// implementations, such as simulated GPUs for tests, only override what they
+        /// model: every default body reports `NVML_ERROR_NOT_SUPPORTED`.
+        #[allow(clippy::missing_safety_doc, clippy::too_many_arguments, unused_variables)]
+        pub trait Backend: Send + Sync {
+            $(
+                unsafe fn $name(&self, $($arg: $ty),*) -> nvmlReturn_t {
+                    nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED
+                }
+            )*
+        }
+
+        impl Backend for NvmlLib {
+            $(
+                unsafe fn $name(&self, $($arg: $ty),*) -> nvmlReturn_t {
+                    match self.$name {
+                        Some(f) => f($($arg),*),
+                        None => nvmlReturn_enum_NVML_ERROR_FUNCTION_NOT_FOUND,
+                    }
+                }
+            )*
+        }
+
+        impl<T: Backend + ?Sized> Backend for &T {
+            $(
+                unsafe fn $name(&self, $($arg: $ty),*) -> nvmlReturn_t {
+                    (**self).$name($($arg),*)
+                }
+            )*
+        }
+    };
+}
+
+impl NvmlLib {
+    /// Opens the first library in [`LIBRARY_NAMES`] that can be loaded.
+    pub fn open() -> Result<NvmlLib, LoadError> {
+        let mut reason = String::new();
+        for name in LIBRARY_NAMES {
+            match Self::open_path(name) {
+                Err(LoadError::LibraryNotFound { reason: r, .. }) => reason = r,
+                result => return result,
+            }
+        }
+        Err(LoadError::LibraryNotFound {
+            names: LIBRARY_NAMES.iter().map(|name| name.to_string()).collect(),
+            reason,
+        })
+    }
+
+    /// Opens the library at `path`, which may be a bare file name resolved by the
+    /// dynamic loader or an absolute path.
+    pub fn open_path(path: &str) -> Result<NvmlLib, LoadError> {
+        unsafe {
+            let library = Library::new(path).map_err(|e| LoadError::LibraryNotFound {
+                names: vec![path.to_owned()],
+                reason: e.to_string(),
+            })?;
+            Self::resolve(library)
+        }
+    }
+}
+
+nvml_library! {
+    fn nvmlInit_v2();
+    fn nvmlInitWithFlags(flags: c_uint);
+    fn nvmlShutdown();
+    fn nvmlSystemGetDriverVersion(version: *mut c_char, length: c_uint);
+    fn nvmlSystemGetNVMLVersion(version: *mut c_char, length: c_uint);
+    fn nvmlSystemGetCudaDriverVersion(cudaDriverVersion: *mut c_int);
+    fn nvmlSystemGetCudaDriverVersion_v2(cudaDriverVersion: *mut c_int);
+    fn nvmlSystemGetProcessName(pid: c_uint, name: *mut c_char, length: c_uint);
+    fn nvmlUnitGetCount(unitCount: *mut c_uint);
+    fn nvmlUnitGetHandleByIndex(index: c_uint, unit: *mut nvmlUnit_t);
+    fn nvmlUnitGetUnitInfo(unit: nvmlUnit_t, info: *mut nvmlUnitInfo_t);
+    fn nvmlUnitGetLedState(unit: nvmlUnit_t, state: *mut nvmlLedState_t);
+    fn nvmlUnitGetPsuInfo(unit: nvmlUnit_t, psu: *mut nvmlPSUInfo_t);
+    fn nvmlUnitGetTemperature(unit: nvmlUnit_t, type_: c_uint, temp: *mut c_uint);
+    fn nvmlUnitGetFanSpeedInfo(unit: nvmlUnit_t, fanSpeeds: *mut nvmlUnitFanSpeeds_t);
+    fn nvmlUnitGetDevices(unit: nvmlUnit_t, deviceCount: *mut c_uint, devices: *mut nvmlDevice_t);
+    fn nvmlSystemGetHicVersion(hwbcCount: *mut c_uint, hwbcEntries: *mut nvmlHwbcEntry_t);
+    fn nvmlDeviceGetCount_v2(deviceCount: *mut c_uint);
+    fn nvmlDeviceGetHandleByIndex_v2(index: c_uint, device: *mut nvmlDevice_t);
+    fn nvmlDeviceGetHandleBySerial(serial: *const c_char, device: *mut nvmlDevice_t);
+    fn nvmlDeviceGetHandleByUUID(uuid: *const c_char, device: *mut nvmlDevice_t);
+    fn nvmlDeviceGetHandleByPciBusId_v2(pciBusId: *const c_char, device: *mut nvmlDevice_t);
+    fn nvmlDeviceGetName(device: nvmlDevice_t, name: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetBrand(device: nvmlDevice_t, type_: *mut nvmlBrandType_t);
+    fn nvmlDeviceGetIndex(device: nvmlDevice_t, index: *mut c_uint);
+    fn nvmlDeviceGetSerial(device: nvmlDevice_t, serial: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetCpuAffinity(device: nvmlDevice_t, cpuSetSize: c_uint, cpuSet: *mut c_ulong);
+    fn nvmlDeviceSetCpuAffinity(device: nvmlDevice_t);
+    fn nvmlDeviceClearCpuAffinity(device: nvmlDevice_t);
+    fn nvmlDeviceGetTopologyCommonAncestor(
+        device1: nvmlDevice_t,
+        device2: nvmlDevice_t,
+        pathInfo: *mut nvmlGpuTopologyLevel_t,
+    );
+    fn nvmlDeviceGetTopologyNearestGpus(
+        device: nvmlDevice_t,
+        level: nvmlGpuTopologyLevel_t,
+        count: *mut c_uint,
+        deviceArray: *mut nvmlDevice_t,
+    );
+    fn nvmlSystemGetTopologyGpuSet(
+        cpuNumber: c_uint,
+        count: *mut c_uint,
+        deviceArray: *mut nvmlDevice_t,
+    );
+    fn nvmlDeviceGetP2PStatus(
+        device1: nvmlDevice_t,
+        device2: nvmlDevice_t,
+        p2pIndex: nvmlGpuP2PCapsIndex_t,
+        p2pStatus: *mut nvmlGpuP2PStatus_t,
+    );
+    fn nvmlDeviceGetUUID(device: nvmlDevice_t, uuid: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetMinorNumber(device: nvmlDevice_t, minorNumber: *mut c_uint);
+    fn nvmlDeviceGetBoardPartNumber(device: nvmlDevice_t, partNumber: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetInforomVersion(
+        device: nvmlDevice_t,
+        object: nvmlInforomObject_t,
+        version: *mut c_char,
+        length: c_uint,
+    );
+    fn nvmlDeviceGetInforomImageVersion(device: nvmlDevice_t, version: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetInforomConfigurationChecksum(device: nvmlDevice_t, checksum: *mut c_uint);
+    fn nvmlDeviceValidateInforom(device: nvmlDevice_t);
+    fn nvmlDeviceGetDisplayMode(device: nvmlDevice_t, display: *mut nvmlEnableState_t);
+    fn nvmlDeviceGetDisplayActive(device: nvmlDevice_t, isActive: *mut nvmlEnableState_t);
+    fn nvmlDeviceGetPersistenceMode(device: nvmlDevice_t, mode: *mut nvmlEnableState_t);
+    fn nvmlDeviceGetPciInfo_v3(device: nvmlDevice_t, pci: *mut nvmlPciInfo_t);
+    fn nvmlDeviceGetMaxPcieLinkGeneration(device: nvmlDevice_t, maxLinkGen: *mut c_uint);
+    fn nvmlDeviceGetMaxPcieLinkWidth(device: nvmlDevice_t, maxLinkWidth: *mut c_uint);
+    fn nvmlDeviceGetCurrPcieLinkGeneration(device: nvmlDevice_t, currLinkGen: *mut c_uint);
+    fn nvmlDeviceGetCurrPcieLinkWidth(device: nvmlDevice_t, currLinkWidth: *mut c_uint);
+    fn nvmlDeviceGetPcieThroughput(
+        device: nvmlDevice_t,
+        counter: nvmlPcieUtilCounter_t,
+        value: *mut c_uint,
+    );
+    fn nvmlDeviceGetPcieReplayCounter(device: nvmlDevice_t, value: *mut c_uint);
+    fn nvmlDeviceGetClockInfo(device: nvmlDevice_t, type_: nvmlClockType_t, clock: *mut c_uint);
+    fn nvmlDeviceGetMaxClockInfo(device: nvmlDevice_t, type_: nvmlClockType_t, clock: *mut c_uint);
+    fn nvmlDeviceGetApplicationsClock(
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    );
+    fn nvmlDeviceGetDefaultApplicationsClock(
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    );
+    fn nvmlDeviceResetApplicationsClocks(device: nvmlDevice_t);
+    fn nvmlDeviceGetClock(
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockId: nvmlClockId_t,
+        clockMHz: *mut c_uint,
+    );
+    fn nvmlDeviceGetMaxCustomerBoostClock(
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    );
+    fn nvmlDeviceGetSupportedMemoryClocks(
+        device: nvmlDevice_t,
+        count: *mut c_uint,
+        clocksMHz: *mut c_uint,
+    );
+    fn nvmlDeviceGetSupportedGraphicsClocks(
+        device: nvmlDevice_t,
+        memoryClockMHz: c_uint,
+        count: *mut c_uint,
+        clocksMHz: *mut c_uint,
+    );
+    fn nvmlDeviceGetAutoBoostedClocksEnabled(
+        device: nvmlDevice_t,
+        isEnabled: *mut nvmlEnableState_t,
+        defaultIsEnabled: *mut nvmlEnableState_t,
+    );
+    fn nvmlDeviceSetAutoBoostedClocksEnabled(device: nvmlDevice_t, enabled: nvmlEnableState_t);
+    fn nvmlDeviceSetDefaultAutoBoostedClocksEnabled(
+        device: nvmlDevice_t,
+        enabled: nvmlEnableState_t,
+        flags: c_uint,
+    );
+    fn nvmlDeviceGetFanSpeed(device: nvmlDevice_t, speed: *mut c_uint);
+    fn nvmlDeviceGetFanSpeed_v2(device: nvmlDevice_t, fan: c_uint, speed: *mut c_uint);
+    fn nvmlDeviceGetTemperature(
+        device: nvmlDevice_t,
+        sensorType: nvmlTemperatureSensors_t,
+        temp: *mut c_uint,
+    );
+    fn nvmlDeviceGetTemperatureThreshold(
+        device: nvmlDevice_t,
+        thresholdType: nvmlTemperatureThresholds_t,
+        temp: *mut c_uint,
+    );
+    fn nvmlDeviceGetPerformanceState(device: nvmlDevice_t, pState: *mut nvmlPstates_t);
+    fn nvmlDeviceGetCurrentClocksThrottleReasons(
+        device: nvmlDevice_t,
+        clocksThrottleReasons: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetSupportedClocksThrottleReasons(
+        device: nvmlDevice_t,
+        supportedClocksThrottleReasons: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetPowerState(device: nvmlDevice_t, pState: *mut nvmlPstates_t);
+    fn nvmlDeviceGetPowerManagementMode(device: nvmlDevice_t, mode: *mut nvmlEnableState_t);
+    fn nvmlDeviceGetPowerManagementLimit(device: nvmlDevice_t, limit: *mut c_uint);
+    fn nvmlDeviceGetPowerManagementLimitConstraints(
+        device: nvmlDevice_t,
+        minLimit: *mut c_uint,
+        maxLimit: *mut c_uint,
+    );
+    fn nvmlDeviceGetPowerManagementDefaultLimit(device: nvmlDevice_t, defaultLimit: *mut c_uint);
+    fn nvmlDeviceGetPowerUsage(device: nvmlDevice_t, power: *mut c_uint);
+    fn nvmlDeviceGetTotalEnergyConsumption(device: nvmlDevice_t, energy: *mut c_ulonglong);
+    fn nvmlDeviceGetEnforcedPowerLimit(device: nvmlDevice_t, limit: *mut c_uint);
+    fn nvmlDeviceGetGpuOperationMode(
+        device: nvmlDevice_t,
+        current: *mut nvmlGpuOperationMode_t,
+        pending: *mut nvmlGpuOperationMode_t,
+    );
+    fn nvmlDeviceGetMemoryInfo(device: nvmlDevice_t, memory: *mut nvmlMemory_t);
+    fn nvmlDeviceGetComputeMode(device: nvmlDevice_t, mode: *mut nvmlComputeMode_t);
+    fn nvmlDeviceGetCudaComputeCapability(
+        device: nvmlDevice_t,
+        major: *mut c_int,
+        minor: *mut c_int,
+    );
+    fn nvmlDeviceGetEccMode(
+        device: nvmlDevice_t,
+        current: *mut nvmlEnableState_t,
+        pending: *mut nvmlEnableState_t,
+    );
+    fn nvmlDeviceGetBoardId(device: nvmlDevice_t, boardId: *mut c_uint);
+    fn nvmlDeviceGetMultiGpuBoard(device: nvmlDevice_t, multiGpuBool: *mut c_uint);
+    fn nvmlDeviceGetTotalEccErrors(
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        eccCounts: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetDetailedEccErrors(
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        eccCounts: *mut nvmlEccErrorCounts_t,
+    );
+    fn nvmlDeviceGetMemoryErrorCounter(
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        locationType: nvmlMemoryLocation_t,
+        count: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetUtilizationRates(device: nvmlDevice_t, utilization: *mut nvmlUtilization_t);
+    fn nvmlDeviceGetEncoderUtilization(
+        device: nvmlDevice_t,
+        utilization: *mut c_uint,
+        samplingPeriodUs: *mut c_uint,
+    );
+    fn nvmlDeviceGetEncoderCapacity(
+        device: nvmlDevice_t,
+        encoderQueryType: nvmlEncoderType_t,
+        encoderCapacity: *mut c_uint,
+    );
+    fn nvmlDeviceGetEncoderStats(
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        averageFps: *mut c_uint,
+        averageLatency: *mut c_uint,
+    );
+    fn nvmlDeviceGetEncoderSessions(
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        sessionInfos: *mut nvmlEncoderSessionInfo_t,
+    );
+    fn nvmlDeviceGetDecoderUtilization(
+        device: nvmlDevice_t,
+        utilization: *mut c_uint,
+        samplingPeriodUs: *mut c_uint,
+    );
+    fn nvmlDeviceGetFBCStats(device: nvmlDevice_t, fbcStats: *mut nvmlFBCStats_t);
+    fn nvmlDeviceGetFBCSessions(
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlFBCSessionInfo_t,
+    );
+    fn nvmlDeviceGetDriverModel(
+        device: nvmlDevice_t,
+        current: *mut nvmlDriverModel_t,
+        pending: *mut nvmlDriverModel_t,
+    );
+    fn nvmlDeviceGetVbiosVersion(device: nvmlDevice_t, version: *mut c_char, length: c_uint);
+    fn nvmlDeviceGetBridgeChipInfo(
+        device: nvmlDevice_t,
+        bridgeHierarchy: *mut nvmlBridgeChipHierarchy_t,
+    );
+    fn nvmlDeviceGetComputeRunningProcesses(
+        device: nvmlDevice_t,
+        infoCount: *mut c_uint,
+        infos: *mut nvmlProcessInfo_t,
+    );
+    fn nvmlDeviceGetGraphicsRunningProcesses(
+        device: nvmlDevice_t,
+        infoCount: *mut c_uint,
+        infos: *mut nvmlProcessInfo_t,
+    );
+    fn nvmlDeviceOnSameBoard(device1: nvmlDevice_t, device2: nvmlDevice_t, onSameBoard: *mut c_int);
+    fn nvmlDeviceGetAPIRestriction(
+        device: nvmlDevice_t,
+        apiType: nvmlRestrictedAPI_t,
+        isRestricted: *mut nvmlEnableState_t,
+    );
+    fn nvmlDeviceGetSamples(
+        device: nvmlDevice_t,
+        type_: nvmlSamplingType_t,
+        lastSeenTimeStamp: c_ulonglong,
+        sampleValType: *mut nvmlValueType_t,
+        sampleCount: *mut c_uint,
+        samples: *mut nvmlSample_t,
+    );
+    fn nvmlDeviceGetBAR1MemoryInfo(device: nvmlDevice_t, bar1Memory: *mut nvmlBAR1Memory_t);
+    fn nvmlDeviceGetViolationStatus(
+        device: nvmlDevice_t,
+        perfPolicyType: nvmlPerfPolicyType_t,
+        violTime: *mut nvmlViolationTime_t,
+    );
+    fn nvmlDeviceGetAccountingMode(device: nvmlDevice_t, mode: *mut nvmlEnableState_t);
+    fn nvmlDeviceGetAccountingStats(
+        device: nvmlDevice_t,
+        pid: c_uint,
+        stats: *mut nvmlAccountingStats_t,
+    );
+    fn nvmlDeviceGetAccountingPids(device: nvmlDevice_t, count: *mut c_uint, pids: *mut c_uint);
+    fn nvmlDeviceGetAccountingBufferSize(device: nvmlDevice_t, bufferSize: *mut c_uint);
+    fn nvmlDeviceGetRetiredPages(
+        device: nvmlDevice_t,
+        cause: nvmlPageRetirementCause_t,
+        pageCount: *mut c_uint,
+        addresses: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetRetiredPages_v2(
+        device: nvmlDevice_t,
+        cause: nvmlPageRetirementCause_t,
+        pageCount: *mut c_uint,
+        addresses: *mut c_ulonglong,
+        timestamps: *mut c_ulonglong,
+    );
+    fn nvmlDeviceGetRetiredPagesPendingStatus(
+        device: nvmlDevice_t,
+        isPending: *mut nvmlEnableState_t,
+    );
+    fn nvmlUnitSetLedState(unit: nvmlUnit_t, color: nvmlLedColor_t);
+    fn nvmlDeviceSetPersistenceMode(device: nvmlDevice_t, mode: nvmlEnableState_t);
+    fn nvmlDeviceSetComputeMode(device: nvmlDevice_t, mode: nvmlComputeMode_t);
+    fn nvmlDeviceSetEccMode(device: nvmlDevice_t, ecc: nvmlEnableState_t);
+    fn nvmlDeviceClearEccErrorCounts(device: nvmlDevice_t, counterType: nvmlEccCounterType_t);
+    fn nvmlDeviceSetDriverModel(
+        device: nvmlDevice_t,
+        driverModel: nvmlDriverModel_t,
+        flags: c_uint,
+    );
+    fn nvmlDeviceSetGpuLockedClocks(
+        device: nvmlDevice_t,
+        minGpuClockMHz: c_uint,
+        maxGpuClockMHz: c_uint,
+    );
+    fn nvmlDeviceResetGpuLockedClocks(device: nvmlDevice_t);
+    fn nvmlDeviceSetApplicationsClocks(
+        device: nvmlDevice_t,
+        memClockMHz: c_uint,
+        graphicsClockMHz: c_uint,
+    );
+    fn nvmlDeviceSetPowerManagementLimit(device: nvmlDevice_t, limit: c_uint);
+    fn nvmlDeviceSetGpuOperationMode(device: nvmlDevice_t, mode: nvmlGpuOperationMode_t);
+    fn nvmlDeviceSetAPIRestriction(
+        device: nvmlDevice_t,
+        apiType: nvmlRestrictedAPI_t,
+        isRestricted: nvmlEnableState_t,
+    );
+    fn nvmlDeviceSetAccountingMode(device: nvmlDevice_t, mode: nvmlEnableState_t);
+    fn nvmlDeviceClearAccountingPids(device: nvmlDevice_t);
+    fn nvmlDeviceGetNvLinkState(
+        device: nvmlDevice_t,
+        link: c_uint,
+        isActive: *mut nvmlEnableState_t,
+    );
+    fn nvmlDeviceGetNvLinkVersion(device: nvmlDevice_t, link: c_uint, version: *mut c_uint);
+    fn nvmlDeviceGetNvLinkCapability(
+        device: nvmlDevice_t,
+        link: c_uint,
+        capability: nvmlNvLinkCapability_t,
+        capResult: *mut c_uint,
+    );
+    fn nvmlDeviceGetNvLinkRemotePciInfo_v2(
+        device: nvmlDevice_t,
+        link: c_uint,
+        pci: *mut nvmlPciInfo_t,
+    );
+    fn nvmlDeviceGetNvLinkErrorCounter(
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: nvmlNvLinkErrorCounter_t,
+        counterValue: *mut c_ulonglong,
+    );
+    fn nvmlDeviceResetNvLinkErrorCounters(device: nvmlDevice_t, link: c_uint);
+    fn nvmlDeviceSetNvLinkUtilizationControl(
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        control: *mut nvmlNvLinkUtilizationControl_t,
+        reset: c_uint,
+    );
+    fn nvmlDeviceGetNvLinkUtilizationControl(
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        control: *mut nvmlNvLinkUtilizationControl_t,
+    );
+    fn nvmlDeviceGetNvLinkUtilizationCounter(
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        rxcounter: *mut c_ulonglong,
+        txcounter: *mut c_ulonglong,
+    );
+    fn nvmlDeviceFreezeNvLinkUtilizationCounter(
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        freeze: nvmlEnableState_t,
+    );
+    fn nvmlDeviceResetNvLinkUtilizationCounter(device: nvmlDevice_t, link: c_uint, counter: c_uint);
+    fn nvmlEventSetCreate(set: *mut nvmlEventSet_t);
+    fn nvmlDeviceRegisterEvents(device: nvmlDevice_t, eventTypes: c_ulonglong, set: nvmlEventSet_t);
+    fn nvmlDeviceGetSupportedEventTypes(device: nvmlDevice_t, eventTypes: *mut c_ulonglong);
+    fn nvmlEventSetWait(set: nvmlEventSet_t, data: *mut nvmlEventData_t, timeoutms: c_uint);
+    fn nvmlEventSetFree(set: nvmlEventSet_t);
+    fn nvmlDeviceModifyDrainState(pciInfo: *mut nvmlPciInfo_t, newState: nvmlEnableState_t);
+    fn nvmlDeviceQueryDrainState(pciInfo: *mut nvmlPciInfo_t, currentState: *mut nvmlEnableState_t);
+    fn nvmlDeviceRemoveGpu_v2(
+        pciInfo: *mut nvmlPciInfo_t,
+        gpuState: nvmlDetachGpuState_t,
+        linkState: nvmlPcieLinkState_t,
+    );
+    fn nvmlDeviceDiscoverGpus(pciInfo: *mut nvmlPciInfo_t);
+    fn nvmlDeviceGetFieldValues(
+        device: nvmlDevice_t,
+        valuesCount: c_int,
+        values: *mut nvmlFieldValue_t,
+    );
+    fn nvmlDeviceGetVirtualizationMode(
+        device: nvmlDevice_t,
+        pVirtualMode: *mut nvmlGpuVirtualizationMode_t,
+    );
+    fn nvmlDeviceSetVirtualizationMode(
+        device: nvmlDevice_t,
+        virtualMode: nvmlGpuVirtualizationMode_t,
+    );
+    fn nvmlDeviceGetSupportedVgpus(
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuTypeIds: *mut nvmlVgpuTypeId_t,
+    );
+    fn nvmlDeviceGetCreatableVgpus(
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuTypeIds: *mut nvmlVgpuTypeId_t,
+    );
+    fn nvmlVgpuTypeGetClass(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeClass: *mut c_char,
+        size: *mut c_uint,
+    );
+    fn nvmlVgpuTypeGetName(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeName: *mut c_char,
+        size: *mut c_uint,
+    );
+    fn nvmlVgpuTypeGetDeviceID(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        deviceID: *mut c_ulonglong,
+        subsystemID: *mut c_ulonglong,
+    );
+    fn nvmlVgpuTypeGetFramebufferSize(vgpuTypeId: nvmlVgpuTypeId_t, fbSize: *mut c_ulonglong);
+    fn nvmlVgpuTypeGetNumDisplayHeads(vgpuTypeId: nvmlVgpuTypeId_t, numDisplayHeads: *mut c_uint);
+    fn nvmlVgpuTypeGetResolution(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        displayIndex: c_uint,
+        xdim: *mut c_uint,
+        ydim: *mut c_uint,
+    );
+    fn nvmlVgpuTypeGetLicense(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeLicenseString: *mut c_char,
+        size: c_uint,
+    );
+    fn nvmlVgpuTypeGetFrameRateLimit(vgpuTypeId: nvmlVgpuTypeId_t, frameRateLimit: *mut c_uint);
+    fn nvmlVgpuTypeGetMaxInstances(
+        device: nvmlDevice_t,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuInstanceCount: *mut c_uint,
+    );
+    fn nvmlVgpuTypeGetMaxInstancesPerVm(
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuInstanceCountPerVm: *mut c_uint,
+    );
+    fn nvmlDeviceGetActiveVgpus(
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuInstances: *mut nvmlVgpuInstance_t,
+    );
+    fn nvmlVgpuInstanceGetVmID(
+        vgpuInstance: nvmlVgpuInstance_t,
+        vmId: *mut c_char,
+        size: c_uint,
+        vmIdType: *mut nvmlVgpuVmIdType_t,
+    );
+    fn nvmlVgpuInstanceGetUUID(vgpuInstance: nvmlVgpuInstance_t, uuid: *mut c_char, size: c_uint);
+    fn nvmlVgpuInstanceGetVmDriverVersion(
+        vgpuInstance: nvmlVgpuInstance_t,
+        version: *mut c_char,
+        length: c_uint,
+    );
+    fn nvmlVgpuInstanceGetFbUsage(vgpuInstance: nvmlVgpuInstance_t, fbUsage: *mut c_ulonglong);
+    fn nvmlVgpuInstanceGetLicenseStatus(vgpuInstance: nvmlVgpuInstance_t, licensed: *mut c_uint);
+    fn nvmlVgpuInstanceGetType(vgpuInstance: nvmlVgpuInstance_t, vgpuTypeId: *mut nvmlVgpuTypeId_t);
+    fn nvmlVgpuInstanceGetFrameRateLimit(
+        vgpuInstance: nvmlVgpuInstance_t,
+        frameRateLimit: *mut c_uint,
+    );
+    fn nvmlVgpuInstanceGetEncoderCapacity(
+        vgpuInstance: nvmlVgpuInstance_t,
+        encoderCapacity: *mut c_uint,
+    );
+    fn nvmlVgpuInstanceSetEncoderCapacity(
+        vgpuInstance: nvmlVgpuInstance_t,
+        encoderCapacity: c_uint,
+    );
+    fn nvmlDeviceGetVgpuUtilization(
+        device: nvmlDevice_t,
+        lastSeenTimeStamp: c_ulonglong,
+        sampleValType: *mut nvmlValueType_t,
+        vgpuInstanceSamplesCount: *mut c_uint,
+        utilizationSamples: *mut nvmlVgpuInstanceUtilizationSample_t,
+    );
+    fn nvmlDeviceGetVgpuProcessUtilization(
+        device: nvmlDevice_t,
+        lastSeenTimeStamp: c_ulonglong,
+        vgpuProcessSamplesCount: *mut c_uint,
+        utilizationSamples: *mut nvmlVgpuProcessUtilizationSample_t,
+    );
+    fn nvmlDeviceGetGridLicensableFeatures_v2(
+        device: nvmlDevice_t,
+        pGridLicensableFeatures: *mut nvmlGridLicensableFeatures_t,
+    );
+    fn nvmlVgpuInstanceGetEncoderStats(
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        averageFps: *mut c_uint,
+        averageLatency: *mut c_uint,
+    );
+    fn nvmlVgpuInstanceGetEncoderSessions(
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlEncoderSessionInfo_t,
+    );
+    fn nvmlVgpuInstanceGetFBCStats(vgpuInstance: nvmlVgpuInstance_t, fbcStats: *mut nvmlFBCStats_t);
+    fn nvmlVgpuInstanceGetFBCSessions(
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlFBCSessionInfo_t,
+    );
+    fn nvmlDeviceGetProcessUtilization(
+        device: nvmlDevice_t,
+        utilization: *mut nvmlProcessUtilizationSample_t,
+        processSamplesCount: *mut c_uint,
+        lastSeenTimeStamp: c_ulonglong,
+    );
+    fn nvmlVgpuInstanceGetAccountingMode(
+        vgpuInstance: nvmlVgpuInstance_t,
+        mode: *mut nvmlEnableState_t,
+    );
+    fn nvmlVgpuInstanceGetAccountingPids(
+        vgpuInstance: nvmlVgpuInstance_t,
+        count: *mut c_uint,
+        pids: *mut c_uint,
+    );
+    fn nvmlVgpuInstanceGetAccountingStats(
+        vgpuInstance: nvmlVgpuInstance_t,
+        pid: c_uint,
+        stats: *mut nvmlAccountingStats_t,
+    );
+    fn nvmlVgpuInstanceGetMetadata(
+        vgpuInstance: nvmlVgpuInstance_t,
+        vgpuMetadata: *mut nvmlVgpuMetadata_t,
+        bufferSize: *mut c_uint,
+    );
+    fn nvmlDeviceGetVgpuMetadata(
+        device: nvmlDevice_t,
+        pgpuMetadata: *mut nvmlVgpuPgpuMetadata_t,
+        bufferSize: *mut c_uint,
+    );
+    fn nvmlGetVgpuCompatibility(
+        vgpuMetadata: *mut nvmlVgpuMetadata_t,
+        pgpuMetadata: *mut nvmlVgpuPgpuMetadata_t,
+        compatibilityInfo: *mut nvmlVgpuPgpuCompatibility_t,
+    );
+    fn nvmlGetVgpuVersion(supported: *mut nvmlVgpuVersion_t, current: *mut nvmlVgpuVersion_t);
+    fn nvmlSetVgpuVersion(vgpuVersion: *mut nvmlVgpuVersion_t);
+    fn nvmlGetBlacklistDeviceCount(deviceCount: *mut c_uint);
+    fn nvmlGetBlacklistDeviceInfoByIndex(index: c_uint, info: *mut nvmlBlacklistDeviceInfo_t);
+}
@@ -5,8 +5,11 @@
 // include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
 pub use bindings::*;
+pub use dynamic::{Backend, LoadError, NvmlLib, LIBRARY_NAMES};
 
+#[allow(clippy::redundant_static_lifetimes)]
 mod bindings;
+mod dynamic;
 
 #[cfg(test)]
 mod tests {
@@ -14,4 +17,14 @@ mod tests {
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn missing_library_is_reported() {
+        match super::NvmlLib::open_path("libnvidia-ml-does-not-exist.so") {
+            Err(super::LoadError::LibraryNotFound { names, .. }) => {
+                assert_eq!(names, vec!["libnvidia-ml-does-not-exist.so".to_owned()])
+            }
+            _ => panic!("expected LibraryNotFound"),
+        }
+    }
 }
@@ -0,0 +1,226 @@
+//! Per-process statistics the driver keeps while accounting mode is on.
+//!
+//! The driver keeps up to [`Handler::get_accounting_buffer_size`] processes and
+//! drops the oldest terminated ones first, so consumers should read the stats of
+//! terminated processes before the buffer wraps around.
+
+use std::os::raw::c_uint;
+use std::time::Duration;
+
+use nvml_binding::*;
+
+use crate::admin::DeviceAdmin;
+use crate::error::{ErrorKind, Result};
+use crate::{query_list, Device, Handler};
+
+/// Accounting statistics of one process.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AccountingStats {
+    pub pid: u32,
+    /// Percent of the lifetime of the process during which a kernel ran on the
+    /// GPU, if the driver could measure it.
+    pub gpu_utilization: Option<u32>,
+    /// Percent of the lifetime of the process during which device memory was
+    /// read or written, if the driver could measure it.
+    pub memory_utilization: Option<u32>,
+    /// Maximum total bytes the process ever had allocated, if known.
+    pub max_memory_usage: Option<u64>,
+    /// CPU timestamp in microseconds at which the process started.
+    pub start_time: u64,
+    /// How long the compute context was active. Drivers report zero until the
+    /// process terminates.
+    pub running_time: Duration,
+    pub is_running: bool,
+}
+
+impl AccountingStats {
+    pub(crate) fn new(pid: u32, stats: &nvmlAccountingStats_t) -> AccountingStats {
+        // NVML_VALUE_NOT_AVAILABLE as an unsigned int and unsigned long long.
+        let available = |value: c_uint| if value == u32::MAX { None } else { Some(value) };
+        AccountingStats {
+            pid,
+            gpu_utilization: available(stats.gpuUtilization),
+            memory_utilization: available(stats.memoryUtilization),
+            max_memory_usage: match stats.maxMemoryUsage {
+                u64::MAX => None,
+                usage => Some(usage),
+            },
+            start_time: stats.startTime,
+            running_time: Duration::from_millis(stats.time),
+            is_running: stats.isRunning != 0,
+        }
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_accounting_mode(&self) -> Result<bool> {
+        unsafe {
+            let mut mode: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self
+                .backend
+                .nvmlDeviceGetAccountingMode(self.dev, &mut mode as *mut nvmlEnableState_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetAccountingMode"));
+            }
+            Ok(mode == nvmlEnableState_enum_NVML_FEATURE_ENABLED)
+        }
+    }
+
+    /// The processes with accounting statistics, running or terminated.
+    pub fn get_accounting_pids(&self) -> Result<Vec<u32>> {
+        unsafe {
+            query_list(|count, pids| {
+                self.backend
+                    .nvmlDeviceGetAccountingPids(self.dev, count, pids)
+            })
+            .map_err(|result| self.error(result, "nvmlDeviceGetAccountingPids"))
+        }
+    }
+
+    pub fn get_accounting_stats(&self, pid: u32) -> Result<AccountingStats> {
+        unsafe {
+            let mut stats: nvmlAccountingStats_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetAccountingStats(
+                self.dev,
+                pid as c_uint,
+                &mut stats as *mut nvmlAccountingStats_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetAccountingStats"));
+            }
+            Ok(AccountingStats::new(pid, &stats))
+        }
+    }
+
+    /// How many processes the driver keeps statistics for.
+    pub fn get_accounting_buffer_size(&self) -> Result<u64> {
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetAccountingBufferSize,
+            "nvmlDeviceGetAccountingBufferSize",
+        )
+    }
+}
+
+impl Device<'_> {
+    /// The statistics of every accounted process, in the order the driver lists
+    /// them.
+    pub fn accounting_stats(&self) -> Result<Vec<AccountingStats>> {
+        let mut stats = vec![];
+        for pid in self.handler.get_accounting_pids()? {
+            match self.handler.get_accounting_stats(pid) {
+                Ok(s) => stats.push(s),
+                // Dropped from the buffer since the pids were listed.
+                Err(ref e) if e.kind() == ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(stats)
+    }
+}
+
+impl DeviceAdmin<'_> {
+    /// Turns accounting on or off. Turning it off discards the statistics
+    /// gathered so far.
+    pub fn set_accounting_mode(&self, enabled: bool) -> Result<()> {
+        let mode = if enabled {
+            nvmlEnableState_enum_NVML_FEATURE_ENABLED
+        } else {
+            nvmlEnableState_enum_NVML_FEATURE_DISABLED
+        };
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceSetAccountingMode(self.handler.dev, mode);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlDeviceSetAccountingMode"));
+            }
+            Ok(())
+        }
+    }
+
+    /// Discards the statistics of terminated processes.
+    pub fn clear_accounting_pids(&self) -> Result<()> {
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceClearAccountingPids(self.handler.dev);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlDeviceClearAccountingPids"));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedAccountedProcess, SimulatedBackend};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn accounting_reports_and_clears_terminated_processes() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(1));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        assert!(!device.handler.get_accounting_mode().unwrap());
+        assert_eq!(
+            device.accounting_stats().unwrap_err().kind(),
+            ErrorKind::NotSupported
+        );
+
+        device.admin().set_accounting_mode(true).unwrap();
+        backend.update(0, |d| {
+            d.accounted_processes = vec![
+                SimulatedAccountedProcess {
+                    pid: 100,
+                    gpu_utilization: 80,
+                    memory_utilization: 30,
+                    max_memory_usage: 1 << 30,
+                    time: 1_500,
+                    start_time: 1_560_000_000_000_000,
+                    running: false,
+                },
+                SimulatedAccountedProcess {
+                    pid: 200,
+                    gpu_utilization: u32::MAX,
+                    memory_utilization: u32::MAX,
+                    max_memory_usage: u64::MAX,
+                    start_time: 1_560_000_100_000_000,
+                    running: true,
+                    ..Default::default()
+                },
+            ]
+        });
+        assert_eq!(device.handler.get_accounting_buffer_size().unwrap(), 4000);
+        let stats = device.accounting_stats().unwrap();
+        assert_eq!(
+            stats[0],
+            AccountingStats {
+                pid: 100,
+                gpu_utilization: Some(80),
+                memory_utilization: Some(30),
+                max_memory_usage: Some(1 << 30),
+                start_time: 1_560_000_000_000_000,
+                running_time: Duration::from_millis(1_500),
+                is_running: false,
+            }
+        );
+        assert_eq!(stats[1].gpu_utilization, None);
+        assert_eq!(stats[1].max_memory_usage, None);
+        assert!(stats[1].is_running);
+        assert_eq!(
+            device.handler.get_accounting_stats(300).unwrap_err().kind(),
+            ErrorKind::NotFound
+        );
+
+        device.admin().clear_accounting_pids().unwrap();
+        assert_eq!(device.handler.get_accounting_pids().unwrap(), vec![200]);
+        device.admin().set_accounting_mode(false).unwrap();
+        device.admin().set_accounting_mode(true).unwrap();
+        assert!(device.accounting_stats().unwrap().is_empty());
+    }
+}
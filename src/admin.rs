@@ -0,0 +1,22 @@
+use crate::{Device, Handler};
+
+/// Operations that change the state of a device, as opposed to the queries on
+/// [`Handler`]. Most need root.
+///
+/// Kept apart so that a call resetting counters or changing settings is always
+/// spelled out as `handler.admin().…` at the call site.
+pub struct DeviceAdmin<'a> {
+    pub(crate) handler: &'a Handler<'a>,
+}
+
+impl Handler<'_> {
+    pub fn admin(&self) -> DeviceAdmin<'_> {
+        DeviceAdmin { handler: self }
+    }
+}
+
+impl Device<'_> {
+    pub fn admin(&self) -> DeviceAdmin<'_> {
+        self.handler.admin()
+    }
+}
@@ -0,0 +1,290 @@
+//! Which CPUs are close to a device.
+//!
+//! [`CpuSet`] reads and prints the list format of `/sys/devices/system/cpu`, such
+//! as `0-7,16-23`, so it can be compared with what the kernel reports.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::os::raw::{c_uint, c_ulong};
+use std::str::FromStr;
+
+use nvml_binding::*;
+
+use crate::error::{Error, Result};
+use crate::{Device, Handler};
+
+// Number of `c_ulong` words passed to `nvmlDeviceGetCpuAffinity`, enough for 4096
+// CPUs with 64-bit words.
+const CPU_SET_WORDS: usize = 64;
+
+const WORD_BITS: u32 = c_ulong::BITS;
+
+// CPU ids at or above this do not fit the mask NVML is given.
+const MAX_CPUS: u32 = CPU_SET_WORDS as u32 * WORD_BITS;
+
+/// A set of logical CPU ids.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CpuSet {
+    cpus: BTreeSet<u32>,
+}
+
+impl CpuSet {
+    pub fn new() -> CpuSet {
+        CpuSet::default()
+    }
+
+    /// The CPUs that are online, from `/sys/devices/system/cpu/online`.
+    pub fn online() -> Result<CpuSet> {
+        let list = std::fs::read_to_string("/sys/devices/system/cpu/online")
+            .map_err(|e| Error::new(&format!("/sys/devices/system/cpu/online: {}", e)))?;
+        list.parse()
+    }
+
+    /// Builds a set from a bitmask in which bit `i` of word `i / c_ulong::BITS`
+    /// stands for CPU `i`, as NVML reports it.
+    pub fn from_mask(words: &[c_ulong]) -> CpuSet {
+        let mut cpus = BTreeSet::new();
+        for (i, word) in words.iter().enumerate() {
+            for bit in 0..WORD_BITS {
+                if word & (1 << bit) != 0 {
+                    cpus.insert(i as u32 * WORD_BITS + bit);
+                }
+            }
+        }
+        CpuSet { cpus }
+    }
+
+    /// The inverse of [`CpuSet::from_mask`].
+    pub fn to_mask(&self) -> Vec<c_ulong> {
+        let len = self
+            .cpus
+            .iter()
+            .next_back()
+            .map_or(0, |max| max / WORD_BITS + 1);
+        let mut words = vec![0; len as usize];
+        for cpu in self.cpus.iter() {
+            words[(cpu / WORD_BITS) as usize] |= 1 << (cpu % WORD_BITS);
+        }
+        words
+    }
+
+    pub fn insert(&mut self, cpu: u32) -> bool {
+        self.cpus.insert(cpu)
+    }
+
+    pub fn remove(&mut self, cpu: u32) -> bool {
+        self.cpus.remove(&cpu)
+    }
+
+    pub fn contains(&self, cpu: u32) -> bool {
+        self.cpus.contains(&cpu)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cpus.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cpus.is_empty()
+    }
+
+    /// The CPUs in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        self.cpus.iter().copied()
+    }
+}
+
+impl std::iter::FromIterator<u32> for CpuSet {
+    fn from_iter<I: IntoIterator<Item = u32>>(iter: I) -> CpuSet {
+        CpuSet {
+            cpus: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Prints the sysfs list format, with ranges for consecutive CPUs: `0-3,8,10-11`.
+impl fmt::Display for CpuSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut cpus = self.cpus.iter().copied().peekable();
+        let mut first = true;
+        while let Some(start) = cpus.next() {
+            let mut end = start;
+            while cpus.peek() == Some(&(end + 1)) {
+                end = cpus.next().unwrap();
+            }
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            if start == end {
+                write!(f, "{}", start)?;
+            } else {
+                write!(f, "{}-{}", start, end)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the sysfs list format; surrounding whitespace, such as the trailing
+/// newline of a sysfs file, is ignored. CPU ids past the mask NVML reports are
+/// rejected.
+impl FromStr for CpuSet {
+    type Err = Error;
+
+    fn from_str(list: &str) -> Result<CpuSet> {
+        let invalid = || Error::invalid_input(&format!("invalid CPU list: {:?}", list));
+        let mut cpus = BTreeSet::new();
+        let list = list.trim();
+        if list.is_empty() {
+            return Ok(CpuSet { cpus });
+        }
+        for range in list.split(',') {
+            let (start, end) = match range.split_once('-') {
+                Some((start, end)) => (start, end),
+                None => (range, range),
+            };
+            let start: u32 = start.trim().parse().map_err(|_| invalid())?;
+            let end: u32 = end.trim().parse().map_err(|_| invalid())?;
+            if start > end || end >= MAX_CPUS {
+                return Err(invalid());
+            }
+            cpus.extend(start..=end);
+        }
+        Ok(CpuSet { cpus })
+    }
+}
+
+impl Handler<'_> {
+    /// The CPUs closest to the device.
+    pub fn get_cpu_affinity(&self) -> Result<CpuSet> {
+        unsafe {
+            let mut words: [c_ulong; CPU_SET_WORDS] = [0; CPU_SET_WORDS];
+            let result = self.backend.nvmlDeviceGetCpuAffinity(
+                self.dev,
+                CPU_SET_WORDS as c_uint,
+                words.as_mut_ptr(),
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetCpuAffinity"));
+            }
+            Ok(CpuSet::from_mask(&words))
+        }
+    }
+
+    /// Restricts the calling thread to the CPUs closest to the device.
+    pub fn set_cpu_affinity(&self) -> Result<()> {
+        unsafe {
+            let result = self.backend.nvmlDeviceSetCpuAffinity(self.dev);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceSetCpuAffinity"));
+            }
+            Ok(())
+        }
+    }
+
+    /// Lets the calling thread run on every CPU again.
+    pub fn clear_cpu_affinity(&self) -> Result<()> {
+        unsafe {
+            let result = self.backend.nvmlDeviceClearCpuAffinity(self.dev);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceClearCpuAffinity"));
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Device<'_> {
+    /// Pins the calling thread to the CPUs closest to the device, usually those of
+    /// its NUMA node, and returns them. Undo with [`Handler::clear_cpu_affinity`].
+    pub fn pin_current_thread(&self) -> Result<CpuSet> {
+        self.handler.set_cpu_affinity()?;
+        Ok(self.cpu_affinity.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn lists_round_trip_through_display() {
+        let cpus: CpuSet = "0-3,5,7-8\n".parse().unwrap();
+        assert_eq!(cpus.iter().collect::<Vec<u32>>(), vec![0, 1, 2, 3, 5, 7, 8]);
+        assert_eq!(cpus.to_string(), "0-3,5,7-8");
+        assert_eq!(" 4 - 6 ,9".parse::<CpuSet>().unwrap().to_string(), "4-6,9");
+        assert!("".parse::<CpuSet>().unwrap().is_empty());
+        assert!("\n".parse::<CpuSet>().unwrap().is_empty());
+    }
+
+    #[test]
+    fn malformed_lists_are_invalid_input() {
+        let too_large = format!("0-{}", MAX_CPUS);
+        for list in [
+            "3-1",
+            "0,x",
+            "0,",
+            "-1",
+            "1-",
+            "0-3-5",
+            "0-4294967295",
+            &too_large,
+        ] {
+            assert_eq!(
+                list.parse::<CpuSet>().unwrap_err().kind(),
+                ErrorKind::InvalidInput,
+                "{:?}",
+                list
+            );
+        }
+    }
+
+    #[test]
+    fn lists_may_name_every_cpu_of_the_mask() {
+        let all: CpuSet = format!("0-{}", MAX_CPUS - 1).parse().unwrap();
+        assert_eq!(all.len(), MAX_CPUS as usize);
+        assert_eq!(all.to_mask(), vec![c_ulong::MAX; CPU_SET_WORDS]);
+    }
+
+    #[test]
+    fn masks_map_bits_to_cpus() {
+        assert!(CpuSet::from_mask(&[0, 0]).is_empty());
+        assert!(CpuSet::new().to_mask().is_empty());
+
+        let cpus = CpuSet::from_mask(&[0b1001, 1]);
+        assert_eq!(cpus.iter().collect::<Vec<u32>>(), vec![0, 3, WORD_BITS]);
+        assert_eq!(cpus.to_mask(), vec![0b1001, 1]);
+
+        let last = std::iter::once(WORD_BITS - 1).collect::<CpuSet>();
+        assert_eq!(last.to_mask(), vec![1 << (WORD_BITS - 1)]);
+        assert_eq!(CpuSet::from_mask(&last.to_mask()), last);
+    }
+
+    #[test]
+    fn cpu_affinity_pins_the_calling_thread() {
+        let mut devices: Vec<SimulatedDevice> = (0..2).map(SimulatedDevice::new).collect();
+        devices[1].cpu_affinity = (8..16).chain(64..72).collect();
+        let backend = Arc::new(SimulatedBackend::new(devices));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+
+        let device = Device::new(&nvml, 1).unwrap();
+        assert_eq!(device.cpu_affinity.to_string(), "8-15,64-71");
+        assert_eq!(device.numa_node, None);
+        assert_eq!(nvml.topology_gpu_set(3).unwrap(), vec![0]);
+        assert_eq!(nvml.topology_gpu_set(64).unwrap(), vec![1]);
+
+        let pinned = device.pin_current_thread().unwrap();
+        assert_eq!(pinned, device.cpu_affinity);
+        assert_eq!(
+            backend.pinned_cpus().unwrap(),
+            pinned.iter().collect::<Vec<u32>>()
+        );
+        device.handler.clear_cpu_affinity().unwrap();
+        assert_eq!(backend.pinned_cpus(), None);
+    }
+}
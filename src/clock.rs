@@ -0,0 +1,350 @@
+use std::os::raw::c_uint;
+
+use nvml_binding::*;
+
+use crate::error::{ErrorKind, Result};
+use crate::{query_list, ClockInfo, Device, Handler};
+
+/// A clock domain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ClockType {
+    Graphics,
+    /// Streaming multiprocessor.
+    Sm,
+    Memory,
+    Video,
+}
+
+impl ClockType {
+    pub const ALL: [ClockType; 4] = [
+        ClockType::Graphics,
+        ClockType::Sm,
+        ClockType::Memory,
+        ClockType::Video,
+    ];
+}
+
+impl From<ClockType> for nvmlClockType_t {
+    fn from(clock_type: ClockType) -> nvmlClockType_t {
+        match clock_type {
+            ClockType::Graphics => nvmlClockType_enum_NVML_CLOCK_GRAPHICS,
+            ClockType::Sm => nvmlClockType_enum_NVML_CLOCK_SM,
+            ClockType::Memory => nvmlClockType_enum_NVML_CLOCK_MEM,
+            ClockType::Video => nvmlClockType_enum_NVML_CLOCK_VIDEO,
+        }
+    }
+}
+
+/// Which clock of a domain to query with [`Handler::get_clock`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ClockId {
+    Current,
+    /// The applications clock target, set by `nvmlDeviceSetApplicationsClocks`.
+    ApplicationsTarget,
+    /// The applications clock the device resets to.
+    ApplicationsDefault,
+    /// The highest boost clock allowed by the board.
+    CustomerBoostMax,
+}
+
+impl From<ClockId> for nvmlClockId_t {
+    fn from(clock_id: ClockId) -> nvmlClockId_t {
+        match clock_id {
+            ClockId::Current => nvmlClockId_enum_NVML_CLOCK_ID_CURRENT,
+            ClockId::ApplicationsTarget => nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_TARGET,
+            ClockId::ApplicationsDefault => nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_DEFAULT,
+            ClockId::CustomerBoostMax => nvmlClockId_enum_NVML_CLOCK_ID_CUSTOMER_BOOST_MAX,
+        }
+    }
+}
+
+/// The clocks of one domain in MHz. Clocks the device does not report are `None`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ClockDomain {
+    pub current: Option<u32>,
+    pub max: Option<u32>,
+    pub applications: Option<u32>,
+    pub default_applications: Option<u32>,
+    pub max_customer_boost: Option<u32>,
+}
+
+/// The clocks of every domain, as part of [`ClockInfo`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ClockDomains {
+    pub graphics: ClockDomain,
+    pub sm: ClockDomain,
+    pub memory: ClockDomain,
+    pub video: ClockDomain,
+}
+
+impl ClockDomains {
+    pub fn domain(&self, clock_type: ClockType) -> &ClockDomain {
+        match clock_type {
+            ClockType::Graphics => &self.graphics,
+            ClockType::Sm => &self.sm,
+            ClockType::Memory => &self.memory,
+            ClockType::Video => &self.video,
+        }
+    }
+}
+
+/// A memory clock and the graphics clocks it can be paired with as applications
+/// clocks, in MHz.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupportedClocks {
+    pub memory: u32,
+    pub graphics: Vec<u32>,
+}
+
+type ClockOfTypeFn =
+    unsafe fn(&(dyn Backend + 'static), nvmlDevice_t, nvmlClockType_t, *mut c_uint) -> nvmlReturn_t;
+
+impl Handler<'_> {
+    /// Current clock of the domain in MHz.
+    pub fn get_clock_info(&self, clock_type: ClockType) -> Result<u32> {
+        self.get_clock_of_type(
+            <dyn Backend>::nvmlDeviceGetClockInfo,
+            "nvmlDeviceGetClockInfo",
+            clock_type,
+        )
+    }
+
+    pub fn get_max_clock_info(&self, clock_type: ClockType) -> Result<u32> {
+        self.get_clock_of_type(
+            <dyn Backend>::nvmlDeviceGetMaxClockInfo,
+            "nvmlDeviceGetMaxClockInfo",
+            clock_type,
+        )
+    }
+
+    pub fn get_applications_clock(&self, clock_type: ClockType) -> Result<u32> {
+        self.get_clock_of_type(
+            <dyn Backend>::nvmlDeviceGetApplicationsClock,
+            "nvmlDeviceGetApplicationsClock",
+            clock_type,
+        )
+    }
+
+    pub fn get_default_applications_clock(&self, clock_type: ClockType) -> Result<u32> {
+        self.get_clock_of_type(
+            <dyn Backend>::nvmlDeviceGetDefaultApplicationsClock,
+            "nvmlDeviceGetDefaultApplicationsClock",
+            clock_type,
+        )
+    }
+
+    pub fn get_max_customer_boost_clock(&self, clock_type: ClockType) -> Result<u32> {
+        self.get_clock_of_type(
+            <dyn Backend>::nvmlDeviceGetMaxCustomerBoostClock,
+            "nvmlDeviceGetMaxCustomerBoostClock",
+            clock_type,
+        )
+    }
+
+    pub fn get_clock(&self, clock_type: ClockType, clock_id: ClockId) -> Result<u32> {
+        unsafe {
+            let mut clock: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetClock(
+                self.dev,
+                clock_type.into(),
+                clock_id.into(),
+                &mut clock as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetClock"));
+            }
+            Ok(clock)
+        }
+    }
+
+    /// Memory clocks usable as applications clocks, in MHz.
+    pub fn get_supported_memory_clocks(&self) -> Result<Vec<u32>> {
+        unsafe {
+            query_list(|count, clocks| {
+                self.backend
+                    .nvmlDeviceGetSupportedMemoryClocks(self.dev, count, clocks)
+            })
+            .map_err(|result| self.error(result, "nvmlDeviceGetSupportedMemoryClocks"))
+        }
+    }
+
+    /// Graphics clocks usable as applications clocks together with the memory
+    /// clock `memory_clock`, in MHz.
+    pub fn get_supported_graphics_clocks(&self, memory_clock: u32) -> Result<Vec<u32>> {
+        unsafe {
+            query_list(|count, clocks| {
+                self.backend.nvmlDeviceGetSupportedGraphicsClocks(
+                    self.dev,
+                    memory_clock as c_uint,
+                    count,
+                    clocks,
+                )
+            })
+            .map_err(|result| self.error(result, "nvmlDeviceGetSupportedGraphicsClocks"))
+        }
+    }
+
+    /// Every supported memory clock with the graphics clocks it pairs with.
+    pub fn get_supported_clocks(&self) -> Result<Vec<SupportedClocks>> {
+        self.get_supported_memory_clocks()?
+            .into_iter()
+            .map(|memory| {
+                Ok(SupportedClocks {
+                    memory,
+                    graphics: self.get_supported_graphics_clocks(memory)?,
+                })
+            })
+            .collect()
+    }
+
+    fn get_clock_of_type(
+        &self,
+        f: ClockOfTypeFn,
+        function: &'static str,
+        clock_type: ClockType,
+    ) -> Result<u32> {
+        unsafe {
+            let mut clock: c_uint = 0;
+            let result = f(
+                &*self.backend,
+                self.dev,
+                clock_type.into(),
+                &mut clock as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, function));
+            }
+            Ok(clock)
+        }
+    }
+
+    /// Every clock of the domain. A clock the device does not report, the driver
+    /// does not implement or the caller may not read is `None`.
+    pub fn get_clock_domain(&self, clock_type: ClockType) -> Result<ClockDomain> {
+        Ok(ClockDomain {
+            current: optional(self.get_clock_info(clock_type))?,
+            max: optional(self.get_max_clock_info(clock_type))?,
+            applications: optional(self.get_applications_clock(clock_type))?,
+            default_applications: optional(self.get_default_applications_clock(clock_type))?,
+            max_customer_boost: optional(self.get_max_customer_boost_clock(clock_type))?,
+        })
+    }
+}
+
+// Older drivers lack some of the clock queries and applications clocks can be
+// restricted to root; neither should hide the clocks that can be read.
+fn optional<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ref e)
+            if matches!(
+                e.kind(),
+                ErrorKind::NotSupported | ErrorKind::FunctionNotFound | ErrorKind::NoPermission
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+impl ClockInfo {
+    pub(crate) fn new(handler: &Handler) -> Result<ClockInfo> {
+        Ok(ClockInfo {
+            cores: handler.get_clock_info(ClockType::Sm)? as u64,
+            memory: handler.get_clock_info(ClockType::Memory)? as u64,
+            domains: ClockDomains {
+                graphics: handler.get_clock_domain(ClockType::Graphics)?,
+                sm: handler.get_clock_domain(ClockType::Sm)?,
+                memory: handler.get_clock_domain(ClockType::Memory)?,
+                video: handler.get_clock_domain(ClockType::Video)?,
+            },
+            supported: optional(handler.get_supported_clocks())?.unwrap_or_default(),
+        })
+    }
+}
+
+impl Device<'_> {
+    /// Reads the clocks again; [`Device::clocks`](crate::Device::clocks) holds
+    /// those read when the device was opened.
+    pub fn clock_info(&self) -> Result<ClockInfo> {
+        ClockInfo::new(&self.handler)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+    use crate::{Error, NVML};
+    use std::sync::Arc;
+
+    #[test]
+    fn unreadable_clocks_are_none() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            applications_clocks_restricted: true,
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let handler = Handler::new(&nvml, 0).unwrap();
+        assert_eq!(
+            handler.get_clock_domain(ClockType::Sm).unwrap(),
+            ClockDomain {
+                current: Some(1530),
+                max: Some(1530),
+                max_customer_boost: Some(1530),
+                ..ClockDomain::default()
+            }
+        );
+        let error = handler
+            .get_clock(ClockType::Sm, ClockId::ApplicationsDefault)
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NoPermission);
+
+        let missing = Error::nvml(
+            nvmlReturn_enum_NVML_ERROR_FUNCTION_NOT_FOUND,
+            "nvmlDeviceGetMaxCustomerBoostClock",
+        );
+        assert_eq!(optional::<u32>(Err(missing)).unwrap(), None);
+
+        backend.update(0, |d| d.lost = true);
+        let error = handler.get_clock_domain(ClockType::Video).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::GpuIsLost);
+    }
+
+    #[test]
+    fn clocks_cover_every_domain() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            max_customer_boost_clocks: [1597, 1597, 877, 1372],
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        let domains = &device.clocks.domains;
+        assert_eq!(domains.sm.current, Some(device.clocks.cores as u32));
+        let graphics = domains.domain(ClockType::Graphics);
+        assert_eq!(graphics.current, Some(1530));
+        assert_eq!(graphics.applications, Some(1312));
+        assert_eq!(graphics.max_customer_boost, Some(1597));
+        assert_eq!(domains.memory.max, Some(877));
+        assert_eq!(
+            device
+                .handler
+                .get_clock(ClockType::Video, ClockId::ApplicationsDefault)
+                .unwrap(),
+            1372
+        );
+
+        let supported = &device.clocks.supported;
+        assert_eq!(supported.len(), 1);
+        assert_eq!(supported[0].memory, 877);
+        assert_eq!(supported[0].graphics, vec![1530, 1312, 1005, 135]);
+        let error = device
+            .handler
+            .get_supported_graphics_clocks(1)
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+        assert_eq!(device.clock_info().unwrap(), device.clocks);
+    }
+}
@@ -0,0 +1,346 @@
+use std::os::raw::c_ulonglong;
+
+use nvml_binding::*;
+
+use crate::admin::DeviceAdmin;
+use crate::error::{not_supported_as_none, Result};
+use crate::{Device, Handler};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MemoryErrorType {
+    /// Errors fixed by ECC, for example single bit errors under SECDED.
+    Corrected,
+    /// Errors ECC could not fix, for example double bit errors under SECDED.
+    Uncorrected,
+}
+
+impl MemoryErrorType {
+    pub const ALL: [MemoryErrorType; 2] =
+        [MemoryErrorType::Corrected, MemoryErrorType::Uncorrected];
+}
+
+impl From<MemoryErrorType> for nvmlMemoryErrorType_t {
+    fn from(error_type: MemoryErrorType) -> nvmlMemoryErrorType_t {
+        match error_type {
+            MemoryErrorType::Corrected => nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_CORRECTED,
+            MemoryErrorType::Uncorrected => {
+                nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_UNCORRECTED
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EccCounterType {
+    /// Errors since the last driver load.
+    Volatile,
+    /// Errors over the lifetime of the device, persisted in the InfoROM.
+    Aggregate,
+}
+
+impl EccCounterType {
+    pub const ALL: [EccCounterType; 2] = [EccCounterType::Volatile, EccCounterType::Aggregate];
+}
+
+impl From<EccCounterType> for nvmlEccCounterType_t {
+    fn from(counter_type: EccCounterType) -> nvmlEccCounterType_t {
+        match counter_type {
+            EccCounterType::Volatile => nvmlEccCounterType_enum_NVML_VOLATILE_ECC,
+            EccCounterType::Aggregate => nvmlEccCounterType_enum_NVML_AGGREGATE_ECC,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MemoryLocation {
+    L1Cache,
+    L2Cache,
+    /// Device memory, or DRAM.
+    DeviceMemory,
+    RegisterFile,
+    TextureMemory,
+    /// Texture shared memory.
+    TextureShm,
+    Cbu,
+    Sram,
+}
+
+impl MemoryLocation {
+    pub const ALL: [MemoryLocation; 8] = [
+        MemoryLocation::L1Cache,
+        MemoryLocation::L2Cache,
+        MemoryLocation::DeviceMemory,
+        MemoryLocation::RegisterFile,
+        MemoryLocation::TextureMemory,
+        MemoryLocation::TextureShm,
+        MemoryLocation::Cbu,
+        MemoryLocation::Sram,
+    ];
+}
+
+impl From<MemoryLocation> for nvmlMemoryLocation_t {
+    fn from(location: MemoryLocation) -> nvmlMemoryLocation_t {
+        match location {
+            MemoryLocation::L1Cache => nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L1_CACHE,
+            MemoryLocation::L2Cache => nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L2_CACHE,
+            MemoryLocation::DeviceMemory => {
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_DEVICE_MEMORY
+            }
+            MemoryLocation::RegisterFile => {
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_REGISTER_FILE
+            }
+            MemoryLocation::TextureMemory => {
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_TEXTURE_MEMORY
+            }
+            MemoryLocation::TextureShm => nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_TEXTURE_SHM,
+            MemoryLocation::Cbu => nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_CBU,
+            MemoryLocation::Sram => nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_SRAM,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EccMode {
+    pub current: bool,
+    /// The mode after the next reboot.
+    pub pending: bool,
+}
+
+/// Per-location counts of `nvmlDeviceGetDetailedEccErrors`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct EccErrorCounts {
+    pub l1_cache: u64,
+    pub l2_cache: u64,
+    pub device_memory: u64,
+    pub register_file: u64,
+}
+
+/// One counter of an [`EccReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EccCounter {
+    pub error_type: MemoryErrorType,
+    pub counter_type: EccCounterType,
+    pub total: u64,
+    /// Counts of the locations the device reports, in [`MemoryLocation::ALL`]
+    /// order.
+    pub locations: Vec<(MemoryLocation, u64)>,
+}
+
+/// The ECC state of a device. `counters` holds every error and counter type
+/// combination the device supports, and is empty when ECC is disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EccReport {
+    pub mode: EccMode,
+    pub counters: Vec<EccCounter>,
+}
+
+impl EccReport {
+    pub fn counter(
+        &self,
+        error_type: MemoryErrorType,
+        counter_type: EccCounterType,
+    ) -> Option<&EccCounter> {
+        self.counters
+            .iter()
+            .find(|c| c.error_type == error_type && c.counter_type == counter_type)
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_ecc_mode(&self) -> Result<EccMode> {
+        unsafe {
+            let mut current: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let mut pending: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self.backend.nvmlDeviceGetEccMode(
+                self.dev,
+                &mut current as *mut nvmlEnableState_t,
+                &mut pending as *mut nvmlEnableState_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetEccMode"));
+            }
+            Ok(EccMode {
+                current: current == nvmlEnableState_enum_NVML_FEATURE_ENABLED,
+                pending: pending == nvmlEnableState_enum_NVML_FEATURE_ENABLED,
+            })
+        }
+    }
+
+    pub fn get_total_ecc_errors(
+        &self,
+        error_type: MemoryErrorType,
+        counter_type: EccCounterType,
+    ) -> Result<u64> {
+        unsafe {
+            let mut count: c_ulonglong = 0;
+            let result = self.backend.nvmlDeviceGetTotalEccErrors(
+                self.dev,
+                error_type.into(),
+                counter_type.into(),
+                &mut count as *mut c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetTotalEccErrors"));
+            }
+            Ok(count)
+        }
+    }
+
+    /// Superseded by [`Handler::get_memory_error_counter`], which covers more
+    /// locations; kept for drivers older than that call.
+    pub fn get_detailed_ecc_errors(
+        &self,
+        error_type: MemoryErrorType,
+        counter_type: EccCounterType,
+    ) -> Result<EccErrorCounts> {
+        unsafe {
+            let mut counts: nvmlEccErrorCounts_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetDetailedEccErrors(
+                self.dev,
+                error_type.into(),
+                counter_type.into(),
+                &mut counts as *mut nvmlEccErrorCounts_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetDetailedEccErrors"));
+            }
+            Ok(EccErrorCounts {
+                l1_cache: counts.l1Cache,
+                l2_cache: counts.l2Cache,
+                device_memory: counts.deviceMemory,
+                register_file: counts.registerFile,
+            })
+        }
+    }
+
+    pub fn get_memory_error_counter(
+        &self,
+        error_type: MemoryErrorType,
+        counter_type: EccCounterType,
+        location: MemoryLocation,
+    ) -> Result<u64> {
+        unsafe {
+            let mut count: c_ulonglong = 0;
+            let result = self.backend.nvmlDeviceGetMemoryErrorCounter(
+                self.dev,
+                error_type.into(),
+                counter_type.into(),
+                location.into(),
+                &mut count as *mut c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetMemoryErrorCounter"));
+            }
+            Ok(count)
+        }
+    }
+
+    fn get_ecc_counter(
+        &self,
+        error_type: MemoryErrorType,
+        counter_type: EccCounterType,
+    ) -> Result<Option<EccCounter>> {
+        let total =
+            match not_supported_as_none(self.get_total_ecc_errors(error_type, counter_type))? {
+                Some(total) => total,
+                None => return Ok(None),
+            };
+        let mut locations = vec![];
+        for location in MemoryLocation::ALL.iter() {
+            let count = self.get_memory_error_counter(error_type, counter_type, *location);
+            if let Some(count) = not_supported_as_none(count)? {
+                locations.push((*location, count));
+            }
+        }
+        Ok(Some(EccCounter {
+            error_type,
+            counter_type,
+            total,
+            locations,
+        }))
+    }
+}
+
+impl Device<'_> {
+    pub fn ecc_report(&self) -> Result<EccReport> {
+        let mode = self.handler.get_ecc_mode()?;
+        let mut counters = vec![];
+        if mode.current {
+            for error_type in MemoryErrorType::ALL.iter() {
+                for counter_type in EccCounterType::ALL.iter() {
+                    if let Some(counter) =
+                        self.handler.get_ecc_counter(*error_type, *counter_type)?
+                    {
+                        counters.push(counter);
+                    }
+                }
+            }
+        }
+        Ok(EccReport { mode, counters })
+    }
+}
+
+impl DeviceAdmin<'_> {
+    /// Resets the ECC error counters of `counter_type` to zero.
+    pub fn clear_ecc_error_counts(&self, counter_type: EccCounterType) -> Result<()> {
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceClearEccErrorCounts(self.handler.dev, counter_type.into());
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlDeviceClearEccErrorCounts"));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::SimulatedBackend;
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn ecc_report_covers_supported_counters() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(1));
+        backend.update(0, |d| {
+            let corrected = nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_CORRECTED as usize;
+            let volatile = nvmlEccCounterType_enum_NVML_VOLATILE_ECC as usize;
+            let dram = nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_DEVICE_MEMORY as usize;
+            d.ecc_errors[corrected][volatile][dram] = 3;
+            d.ecc_errors[corrected][volatile][0] = 1;
+        });
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        let report = device.ecc_report().unwrap();
+        assert!(report.mode.current);
+        assert_eq!(report.counters.len(), 4);
+        let counter = report
+            .counter(MemoryErrorType::Corrected, EccCounterType::Volatile)
+            .unwrap();
+        assert_eq!(counter.total, 4);
+        assert_eq!(counter.locations.len(), 4);
+        assert_eq!(counter.locations[2], (MemoryLocation::DeviceMemory, 3));
+        let detailed = device
+            .handler
+            .get_detailed_ecc_errors(MemoryErrorType::Corrected, EccCounterType::Volatile)
+            .unwrap();
+        assert_eq!(detailed.l1_cache, 1);
+
+        device
+            .admin()
+            .clear_ecc_error_counts(EccCounterType::Volatile)
+            .unwrap();
+        let report = device.ecc_report().unwrap();
+        let counter = report
+            .counter(MemoryErrorType::Corrected, EccCounterType::Volatile)
+            .unwrap();
+        assert_eq!(counter.total, 0);
+
+        backend.update(0, |d| d.ecc_enabled = false);
+        assert!(device.ecc_report().unwrap().counters.is_empty());
+    }
+}
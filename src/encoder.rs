@@ -0,0 +1,350 @@
+//! Video encoder (NVENC) and frame buffer capture (NvFBC) sessions.
+//!
+//! The same session types describe the sessions of a whole device and those of
+//! one [`VgpuInstance`](crate::vgpu::VgpuInstance).
+
+use std::os::raw::c_uint;
+use std::time::Duration;
+
+use bitflags::bitflags;
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::{query_list, Device, Handler};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EncoderType {
+    H264,
+    Hevc,
+}
+
+impl EncoderType {
+    pub const ALL: [EncoderType; 2] = [EncoderType::H264, EncoderType::Hevc];
+
+    fn from_raw(encoder_type: nvmlEncoderType_t) -> Option<EncoderType> {
+        match encoder_type {
+            nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_H264 => Some(EncoderType::H264),
+            nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_HEVC => Some(EncoderType::Hevc),
+            _ => None,
+        }
+    }
+}
+
+impl From<EncoderType> for nvmlEncoderType_t {
+    fn from(encoder_type: EncoderType) -> nvmlEncoderType_t {
+        match encoder_type {
+            EncoderType::H264 => nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_H264,
+            EncoderType::Hevc => nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_HEVC,
+        }
+    }
+}
+
+/// Totals over the active encoder sessions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EncoderStats {
+    pub session_count: u32,
+    pub average_fps: u32,
+    pub average_latency: Duration,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EncoderSession {
+    pub session_id: u32,
+    pub pid: u32,
+    /// The owning vGPU instance, on vGPU hosts.
+    pub vgpu_instance: Option<u32>,
+    /// `None` for codecs this crate does not know.
+    pub codec: Option<EncoderType>,
+    /// Current encode resolution, horizontal then vertical.
+    pub resolution: (u32, u32),
+    /// Moving average of encoded frames per second.
+    pub average_fps: u32,
+    /// Moving average of the encode latency.
+    pub average_latency: Duration,
+}
+
+impl From<&nvmlEncoderSessionInfo_t> for EncoderSession {
+    fn from(info: &nvmlEncoderSessionInfo_t) -> EncoderSession {
+        EncoderSession {
+            session_id: info.sessionId,
+            pid: info.pid,
+            vgpu_instance: vgpu_instance(info.vgpuInstance),
+            codec: EncoderType::from_raw(info.codecType),
+            resolution: (info.hResolution, info.vResolution),
+            average_fps: info.averageFps,
+            average_latency: Duration::from_micros(info.averageLatency as u64),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FbcSessionType {
+    Unknown,
+    /// Captures to system memory.
+    ToSys,
+    /// Captures to a CUDA buffer.
+    Cuda,
+    /// Captures to a video memory surface.
+    Vid,
+    /// Captures into the hardware encoder.
+    HwEnc,
+}
+
+impl From<nvmlFBCSessionType_t> for FbcSessionType {
+    fn from(session_type: nvmlFBCSessionType_t) -> FbcSessionType {
+        match session_type {
+            nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_TOSYS => FbcSessionType::ToSys,
+            nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_CUDA => FbcSessionType::Cuda,
+            nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_VID => FbcSessionType::Vid,
+            nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_HWENC => FbcSessionType::HwEnc,
+            _ => FbcSessionType::Unknown,
+        }
+    }
+}
+
+bitflags! {
+    /// The `NVML_NVFBC_SESSION_FLAG_*` bitmask.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct FbcSessionFlags: u32 {
+        const DIFFMAP_ENABLED = NVML_NVFBC_SESSION_FLAG_DIFFMAP_ENABLED;
+        const CLASSIFICATIONMAP_ENABLED = NVML_NVFBC_SESSION_FLAG_CLASSIFICATIONMAP_ENABLED;
+        const CAPTURE_WITH_WAIT_NO_WAIT = NVML_NVFBC_SESSION_FLAG_CAPTURE_WITH_WAIT_NO_WAIT;
+        const CAPTURE_WITH_WAIT_INFINITE = NVML_NVFBC_SESSION_FLAG_CAPTURE_WITH_WAIT_INFINITE;
+        const CAPTURE_WITH_WAIT_TIMEOUT = NVML_NVFBC_SESSION_FLAG_CAPTURE_WITH_WAIT_TIMEOUT;
+    }
+}
+
+/// Totals over the active frame buffer capture sessions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FbcStats {
+    pub session_count: u32,
+    /// Moving average of new frames captured per second.
+    pub average_fps: u32,
+    pub average_latency: Duration,
+}
+
+impl From<&nvmlFBCStats_t> for FbcStats {
+    fn from(stats: &nvmlFBCStats_t) -> FbcStats {
+        FbcStats {
+            session_count: stats.sessionsCount,
+            average_fps: stats.averageFPS,
+            average_latency: Duration::from_micros(stats.averageLatency as u64),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FbcSession {
+    pub session_id: u32,
+    pub pid: u32,
+    /// The owning vGPU instance, on vGPU hosts.
+    pub vgpu_instance: Option<u32>,
+    pub display_ordinal: u32,
+    pub session_type: FbcSessionType,
+    pub flags: FbcSessionFlags,
+    /// The largest resolution the session supports, horizontal then vertical.
+    pub max_resolution: (u32, u32),
+    /// The resolution of the last capture call.
+    pub resolution: (u32, u32),
+    pub average_fps: u32,
+    pub average_latency: Duration,
+}
+
+impl From<&nvmlFBCSessionInfo_t> for FbcSession {
+    fn from(info: &nvmlFBCSessionInfo_t) -> FbcSession {
+        FbcSession {
+            session_id: info.sessionId,
+            pid: info.pid,
+            vgpu_instance: vgpu_instance(info.vgpuInstance),
+            display_ordinal: info.displayOrdinal,
+            session_type: info.sessionType.into(),
+            flags: FbcSessionFlags::from_bits_retain(info.sessionFlags),
+            max_resolution: (info.hMaxResolution, info.vMaxResolution),
+            resolution: (info.hResolution, info.vResolution),
+            average_fps: info.averageFPS,
+            average_latency: Duration::from_micros(info.averageLatency as u64),
+        }
+    }
+}
+
+// Sessions report instance 0 outside of vGPU hosts.
+fn vgpu_instance(instance: nvmlVgpuInstance_t) -> Option<u32> {
+    match instance {
+        0 => None,
+        instance => Some(instance),
+    }
+}
+
+impl Handler<'_> {
+    /// The share of the encoder capacity still available for `codec`, in percent.
+    pub fn get_encoder_capacity(&self, codec: EncoderType) -> Result<u32> {
+        unsafe {
+            let mut capacity: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetEncoderCapacity(
+                self.dev,
+                codec.into(),
+                &mut capacity as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetEncoderCapacity"));
+            }
+            Ok(capacity)
+        }
+    }
+
+    pub fn get_encoder_stats(&self) -> Result<EncoderStats> {
+        unsafe {
+            let mut session_count: c_uint = 0;
+            let mut average_fps: c_uint = 0;
+            let mut average_latency: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetEncoderStats(
+                self.dev,
+                &mut session_count as *mut c_uint,
+                &mut average_fps as *mut c_uint,
+                &mut average_latency as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetEncoderStats"));
+            }
+            Ok(EncoderStats {
+                session_count,
+                average_fps,
+                average_latency: Duration::from_micros(average_latency as u64),
+            })
+        }
+    }
+
+    pub fn get_encoder_sessions(&self) -> Result<Vec<EncoderSession>> {
+        let sessions = unsafe {
+            query_list(|count, sessions| {
+                self.backend
+                    .nvmlDeviceGetEncoderSessions(self.dev, count, sessions)
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetEncoderSessions"))?;
+        Ok(sessions.iter().map(EncoderSession::from).collect())
+    }
+
+    pub fn get_fbc_stats(&self) -> Result<FbcStats> {
+        unsafe {
+            let mut stats: nvmlFBCStats_t = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlDeviceGetFBCStats(self.dev, &mut stats as *mut nvmlFBCStats_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetFBCStats"));
+            }
+            Ok(FbcStats::from(&stats))
+        }
+    }
+
+    pub fn get_fbc_sessions(&self) -> Result<Vec<FbcSession>> {
+        let sessions = unsafe {
+            query_list(|count, sessions| {
+                self.backend
+                    .nvmlDeviceGetFBCSessions(self.dev, count, sessions)
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetFBCSessions"))?;
+        Ok(sessions.iter().map(FbcSession::from).collect())
+    }
+}
+
+impl Device<'_> {
+    /// The share of the encoder capacity still available for `codec`, in percent.
+    pub fn encoder_capacity(&self, codec: EncoderType) -> Result<u32> {
+        self.handler.get_encoder_capacity(codec)
+    }
+
+    /// Totals over the encoder sessions of every process and vGPU on the device.
+    pub fn encoder_stats(&self) -> Result<EncoderStats> {
+        self.handler.get_encoder_stats()
+    }
+
+    pub fn encoder_sessions(&self) -> Result<Vec<EncoderSession>> {
+        self.handler.get_encoder_sessions()
+    }
+
+    /// Totals over the frame buffer capture sessions of every process and vGPU on
+    /// the device.
+    pub fn fbc_stats(&self) -> Result<FbcStats> {
+        self.handler.get_fbc_stats()
+    }
+
+    pub fn fbc_sessions(&self) -> Result<Vec<FbcSession>> {
+        self.handler.get_fbc_sessions()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{
+        SimulatedBackend, SimulatedDevice, SimulatedEncoderSession, SimulatedFbcSession,
+        SimulatedVgpuInstance, SimulatedVgpuType,
+    };
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn encoder_and_fbc_sessions_cover_host_and_guests() {
+        let mut device = SimulatedDevice::new(0);
+        device.encoder_capacity = 60;
+        device.encoder_sessions = vec![SimulatedEncoderSession {
+            session_id: 1,
+            pid: 100,
+            codec: nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_HEVC,
+            resolution: (3840, 2160),
+            average_fps: 20,
+            average_latency: 4000,
+        }];
+        device.fbc_sessions = vec![SimulatedFbcSession {
+            session_id: 5,
+            pid: 100,
+            session_type: nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_HWENC,
+            flags: NVML_NVFBC_SESSION_FLAG_DIFFMAP_ENABLED,
+            average_fps: 30,
+            ..Default::default()
+        }];
+        device.vgpu_types = vec![SimulatedVgpuType::new(11, "GRID V100-4Q")];
+        let mut instance = SimulatedVgpuInstance::new(7, 11);
+        instance.encoder_sessions = vec![SimulatedEncoderSession {
+            session_id: 2,
+            pid: 300,
+            average_fps: 40,
+            average_latency: 2000,
+            ..Default::default()
+        }];
+        device.vgpu_instances = vec![instance];
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(vec![
+            device,
+            SimulatedDevice::new(1),
+        ])))
+        .unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+
+        assert_eq!(device.encoder_capacity(EncoderType::H264).unwrap(), 60);
+        let stats = device.encoder_stats().unwrap();
+        assert_eq!(stats.session_count, 2);
+        assert_eq!(stats.average_fps, 30);
+        assert_eq!(stats.average_latency, Duration::from_millis(3));
+        let sessions = device.encoder_sessions().unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].codec, Some(EncoderType::Hevc));
+        assert_eq!(sessions[0].resolution, (3840, 2160));
+        assert_eq!(sessions[0].vgpu_instance, None);
+        assert_eq!(sessions[1].pid, 300);
+        assert_eq!(sessions[1].vgpu_instance, Some(7));
+
+        assert_eq!(device.fbc_stats().unwrap().average_fps, 30);
+        let sessions = device.fbc_sessions().unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].session_type, FbcSessionType::HwEnc);
+        assert_eq!(sessions[0].flags, FbcSessionFlags::DIFFMAP_ENABLED);
+        assert_eq!(sessions[0].max_resolution, (4096, 2160));
+
+        let idle = Device::new(&nvml, 1).unwrap();
+        assert!(idle.encoder_sessions().unwrap().is_empty());
+        assert_eq!(idle.encoder_stats().unwrap().session_count, 0);
+    }
+}
@@ -1,35 +1,320 @@
-#[derive(Debug)]
-pub struct Error {
-    message: Option<String>,
+use std::fmt;
+
+use nvml_binding::*;
+
+/// The category of an [`Error`], one variant per `nvmlReturn_t` failure code plus
+/// [`ErrorKind::InvalidInput`] for failures on the side of this crate.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// NVML was not first initialized with `nvmlInit`.
+    Uninitialized,
+    /// A supplied argument is invalid.
+    InvalidArgument,
+    /// The requested operation is not available on the target device.
+    NotSupported,
+    /// The current user does not have permission for the operation.
+    NoPermission,
+    /// Multiple initializations are now allowed through ref counting.
+    AlreadyInitialized,
+    /// A query to find an object was unsuccessful.
+    NotFound,
+    /// An input argument is not large enough.
+    InsufficientSize,
+    /// A device's external power cables are not properly attached.
+    InsufficientPower,
+    /// The NVIDIA driver is not loaded.
+    DriverNotLoaded,
+    /// A user provided timeout passed.
+    Timeout,
+    /// The NVIDIA kernel detected an interrupt issue with a GPU.
+    IrqIssue,
+    /// The NVML shared library couldn't be found or loaded.
+    LibraryNotFound,
+    /// The local version of NVML doesn't implement this function.
+    FunctionNotFound,
+    /// The infoROM is corrupted.
+    CorruptedInforom,
+    /// The GPU has fallen off the bus or has otherwise become inaccessible.
+    GpuIsLost,
+    /// The GPU requires a reset before it can be used again.
+    ResetRequired,
+    /// The GPU control device has been blocked by the operating system or cgroups.
+    OperatingSystem,
+    /// The driver and library versions do not match.
+    LibRmVersionMismatch,
+    /// The operation cannot be performed because the GPU is currently in use.
+    InUse,
+    /// Insufficient memory.
+    Memory,
+    /// No data.
+    NoData,
+    /// The requested vGPU operation is not available because ECC is enabled.
+    VgpuEccNotSupported,
+    /// An internal driver error occurred.
+    Unknown,
+    /// A return code not defined by the vendored `nvml.h`.
+    Unrecognized(nvmlReturn_t),
+    /// Input that does not come from an NVML call, such as a selector string, a
+    /// sysfs file or a metadata blob, failed to parse or validate.
+    InvalidInput,
 }
 
-impl Default for Error {
-    fn default() -> Error {
-        Error { message: None }
+impl ErrorKind {
+    pub fn from_code(code: nvmlReturn_t) -> ErrorKind {
+        match code {
+            nvmlReturn_enum_NVML_ERROR_UNINITIALIZED => ErrorKind::Uninitialized,
+            nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT => ErrorKind::InvalidArgument,
+            nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED => ErrorKind::NotSupported,
+            nvmlReturn_enum_NVML_ERROR_NO_PERMISSION => ErrorKind::NoPermission,
+            nvmlReturn_enum_NVML_ERROR_ALREADY_INITIALIZED => ErrorKind::AlreadyInitialized,
+            nvmlReturn_enum_NVML_ERROR_NOT_FOUND => ErrorKind::NotFound,
+            nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE => ErrorKind::InsufficientSize,
+            nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_POWER => ErrorKind::InsufficientPower,
+            nvmlReturn_enum_NVML_ERROR_DRIVER_NOT_LOADED => ErrorKind::DriverNotLoaded,
+            nvmlReturn_enum_NVML_ERROR_TIMEOUT => ErrorKind::Timeout,
+            nvmlReturn_enum_NVML_ERROR_IRQ_ISSUE => ErrorKind::IrqIssue,
+            nvmlReturn_enum_NVML_ERROR_LIBRARY_NOT_FOUND => ErrorKind::LibraryNotFound,
+            nvmlReturn_enum_NVML_ERROR_FUNCTION_NOT_FOUND => ErrorKind::FunctionNotFound,
+            nvmlReturn_enum_NVML_ERROR_CORRUPTED_INFOROM => ErrorKind::CorruptedInforom,
+            nvmlReturn_enum_NVML_ERROR_GPU_IS_LOST => ErrorKind::GpuIsLost,
+            nvmlReturn_enum_NVML_ERROR_RESET_REQUIRED => ErrorKind::ResetRequired,
+            nvmlReturn_enum_NVML_ERROR_OPERATING_SYSTEM => ErrorKind::OperatingSystem,
+            nvmlReturn_enum_NVML_ERROR_LIB_RM_VERSION_MISMATCH => ErrorKind::LibRmVersionMismatch,
+            nvmlReturn_enum_NVML_ERROR_IN_USE => ErrorKind::InUse,
+            nvmlReturn_enum_NVML_ERROR_MEMORY => ErrorKind::Memory,
+            nvmlReturn_enum_NVML_ERROR_NO_DATA => ErrorKind::NoData,
+            nvmlReturn_enum_NVML_ERROR_VGPU_ECC_NOT_SUPPORTED => ErrorKind::VgpuEccNotSupported,
+            nvmlReturn_enum_NVML_ERROR_UNKNOWN => ErrorKind::Unknown,
+            code => ErrorKind::Unrecognized(code),
+        }
+    }
+
+    /// The raw `nvmlReturn_t` value of this kind; `NVML_ERROR_INVALID_ARGUMENT` for
+    /// [`ErrorKind::InvalidInput`], which NVML never returns.
+    pub fn code(self) -> nvmlReturn_t {
+        match self {
+            ErrorKind::Uninitialized => nvmlReturn_enum_NVML_ERROR_UNINITIALIZED,
+            ErrorKind::InvalidArgument => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            ErrorKind::NotSupported => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+            ErrorKind::NoPermission => nvmlReturn_enum_NVML_ERROR_NO_PERMISSION,
+            ErrorKind::AlreadyInitialized => nvmlReturn_enum_NVML_ERROR_ALREADY_INITIALIZED,
+            ErrorKind::NotFound => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            ErrorKind::InsufficientSize => nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE,
+            ErrorKind::InsufficientPower => nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_POWER,
+            ErrorKind::DriverNotLoaded => nvmlReturn_enum_NVML_ERROR_DRIVER_NOT_LOADED,
+            ErrorKind::Timeout => nvmlReturn_enum_NVML_ERROR_TIMEOUT,
+            ErrorKind::IrqIssue => nvmlReturn_enum_NVML_ERROR_IRQ_ISSUE,
+            ErrorKind::LibraryNotFound => nvmlReturn_enum_NVML_ERROR_LIBRARY_NOT_FOUND,
+            ErrorKind::FunctionNotFound => nvmlReturn_enum_NVML_ERROR_FUNCTION_NOT_FOUND,
+            ErrorKind::CorruptedInforom => nvmlReturn_enum_NVML_ERROR_CORRUPTED_INFOROM,
+            ErrorKind::GpuIsLost => nvmlReturn_enum_NVML_ERROR_GPU_IS_LOST,
+            ErrorKind::ResetRequired => nvmlReturn_enum_NVML_ERROR_RESET_REQUIRED,
+            ErrorKind::OperatingSystem => nvmlReturn_enum_NVML_ERROR_OPERATING_SYSTEM,
+            ErrorKind::LibRmVersionMismatch => nvmlReturn_enum_NVML_ERROR_LIB_RM_VERSION_MISMATCH,
+            ErrorKind::InUse => nvmlReturn_enum_NVML_ERROR_IN_USE,
+            ErrorKind::Memory => nvmlReturn_enum_NVML_ERROR_MEMORY,
+            ErrorKind::NoData => nvmlReturn_enum_NVML_ERROR_NO_DATA,
+            ErrorKind::VgpuEccNotSupported => nvmlReturn_enum_NVML_ERROR_VGPU_ECC_NOT_SUPPORTED,
+            ErrorKind::Unknown => nvmlReturn_enum_NVML_ERROR_UNKNOWN,
+            ErrorKind::Unrecognized(code) => code,
+            ErrorKind::InvalidInput => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            ErrorKind::Uninitialized => "Uninitialized",
+            ErrorKind::InvalidArgument => "Invalid Argument",
+            ErrorKind::NotSupported => "Not Supported",
+            ErrorKind::NoPermission => "Insufficient Permissions",
+            ErrorKind::AlreadyInitialized => "Already Initialized",
+            ErrorKind::NotFound => "Not Found",
+            ErrorKind::InsufficientSize => "Insufficient Size",
+            ErrorKind::InsufficientPower => "Insufficient External Power",
+            ErrorKind::DriverNotLoaded => "Driver Not Loaded",
+            ErrorKind::Timeout => "Timeout",
+            ErrorKind::IrqIssue => "Interrupt request issue",
+            ErrorKind::LibraryNotFound => "NVML Shared Library Not Found",
+            ErrorKind::FunctionNotFound => "Function Not Found",
+            ErrorKind::CorruptedInforom => "Corrupted infoROM",
+            ErrorKind::GpuIsLost => "GPU is lost",
+            ErrorKind::ResetRequired => "GPU requires reset",
+            ErrorKind::OperatingSystem => "GPU access blocked by the operating system",
+            ErrorKind::LibRmVersionMismatch => "Driver/library version mismatch",
+            ErrorKind::InUse => "In use by another client",
+            ErrorKind::Memory => "Insufficient Memory",
+            ErrorKind::NoData => "No data",
+            ErrorKind::VgpuEccNotSupported => "vGPU operation not supported with ECC enabled",
+            ErrorKind::Unknown => "Unknown Error",
+            ErrorKind::Unrecognized(_) => "Unrecognized Error",
+            ErrorKind::InvalidInput => "Invalid Input",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
     }
 }
 
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    code: Option<nvmlReturn_t>,
+    function: Option<&'static str>,
+    device_index: Option<u32>,
+    device_uuid: Option<String>,
+    message: Option<String>,
+    load_error: Option<Box<LoadError>>,
+}
+
 impl Error {
+    /// An error that did not come from an NVML return code.
     pub fn new(message: &str) -> Error {
         Error {
             message: Some(message.into()),
+            ..Error::with_kind(ErrorKind::Unknown)
+        }
+    }
+
+    /// Input that failed to parse or validate, of kind [`ErrorKind::InvalidInput`].
+    pub fn invalid_input(message: &str) -> Error {
+        Error {
+            message: Some(message.into()),
+            ..Error::with_kind(ErrorKind::InvalidInput)
+        }
+    }
+
+    /// The error `function` reported by returning `code`.
+    pub fn nvml(code: nvmlReturn_t, function: &'static str) -> Error {
+        Error {
+            code: Some(code),
+            function: Some(function),
+            ..Error::with_kind(ErrorKind::from_code(code))
+        }
+    }
+
+    fn with_kind(kind: ErrorKind) -> Error {
+        Error {
+            kind,
+            code: None,
+            function: None,
+            device_index: None,
+            device_uuid: None,
+            message: None,
+            load_error: None,
+        }
+    }
+
+    /// Records the index of the device the failing call was made on.
+    pub fn with_device_index(mut self, index: u32) -> Error {
+        self.device_index = Some(index);
+        self
+    }
+
+    /// Records the UUID of the device the failing call was made on.
+    pub fn with_device_uuid(mut self, uuid: &str) -> Error {
+        self.device_uuid = Some(uuid.to_owned());
+        self
+    }
+
+    /// Describes the error with `message` instead of the description of its kind.
+    pub fn with_message(mut self, message: &str) -> Error {
+        self.message = Some(message.to_owned());
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The raw `nvmlReturn_t`, if the error came from an NVML call.
+    pub fn code(&self) -> Option<nvmlReturn_t> {
+        self.code
+    }
+
+    /// The NVML function that failed.
+    pub fn function(&self) -> Option<&'static str> {
+        self.function
+    }
+
+    pub fn device_index(&self) -> Option<u32> {
+        self.device_index
+    }
+
+    pub fn device_uuid(&self) -> Option<&str> {
+        self.device_uuid.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Returns why libnvidia-ml could not be loaded, if that is what failed.
+    pub fn load_error(&self) -> Option<&LoadError> {
+        self.load_error.as_deref()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(function) = self.function {
+            write!(f, "{}", function)?;
+            match (self.device_index, &self.device_uuid) {
+                (Some(index), Some(uuid)) => write!(f, " on device {} ({})", index, uuid)?,
+                (Some(index), None) => write!(f, " on device {}", index)?,
+                (None, Some(uuid)) => write!(f, " on device {}", uuid)?,
+                (None, None) => {}
+            }
+            f.write_str(": ")?;
+        }
+        match (&self.message, self.code) {
+            (Some(message), _) => f.write_str(message),
+            (None, Some(code)) => write!(f, "{} (code {})", self.kind, code),
+            (None, None) => write!(f, "{}", self.kind),
         }
     }
 }
 
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.load_error
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
-impl From<nvml_binding::nvmlReturn_t> for Error {
-    fn from(r: nvml_binding::nvmlReturn_t) -> Error {
-        unsafe {
-            if r == nvml_binding::nvmlReturn_enum_NVML_SUCCESS {
-                return Error { message: None };
-            }
-            let ptr = nvml_binding::nvmlErrorString(r);
-            let message = std::ffi::CStr::from_ptr(ptr).to_str().unwrap().to_owned();
-            Error {
-                message: Some(message),
-            }
+// For optional features: maps `NotSupported` to `None` and keeps other failures.
+pub(crate) fn not_supported_as_none<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ref e) if e.kind() == ErrorKind::NotSupported => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+impl From<nvmlReturn_t> for Error {
+    fn from(r: nvmlReturn_t) -> Error {
+        Error {
+            code: Some(r),
+            ..Error::with_kind(ErrorKind::from_code(r))
+        }
+    }
+}
+
+impl From<LoadError> for Error {
+    fn from(e: LoadError) -> Error {
+        let kind = match e {
+            LoadError::LibraryNotFound { .. } => ErrorKind::LibraryNotFound,
+            LoadError::SymbolMissing { .. } => ErrorKind::FunctionNotFound,
+        };
+        Error {
+            message: Some(e.to_string()),
+            load_error: Some(Box::new(e)),
+            ..Error::with_kind(kind)
         }
     }
 }
@@ -0,0 +1,304 @@
+//! Event subscription on top of `nvmlEventSet_t`.
+//!
+//! An [`EventSet`] collects events of the device types registered on it. Events are
+//! either pulled with [`EventSet::wait`] or, after [`EventSet::listen`], forwarded
+//! by a background thread into a channel.
+
+use std::collections::HashMap;
+use std::os::raw::{c_uint, c_ulonglong};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, TryRecvError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use bitflags::bitflags;
+use nvml_binding::*;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::{Handler, NVML};
+
+bitflags! {
+    /// The `nvmlEventType*` bitmask.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct EventTypes: u64 {
+        /// A corrected single bit ECC error.
+        const SINGLE_BIT_ECC_ERROR = nvmlEventTypeSingleBitEccError as u64;
+        /// An uncorrected double bit ECC error.
+        const DOUBLE_BIT_ECC_ERROR = nvmlEventTypeDoubleBitEccError as u64;
+        /// A change of the performance state.
+        const PSTATE = nvmlEventTypePState as u64;
+        /// A critical Xid error; the Xid is carried by [`Event::xid`].
+        const XID_CRITICAL_ERROR = nvmlEventTypeXidCriticalError as u64;
+        /// A clock change.
+        const CLOCK = nvmlEventTypeClock as u64;
+    }
+}
+
+/// An event reported by a device registered on an [`EventSet`].
+#[derive(Clone)]
+pub struct Event<'nvml> {
+    pub device: Handler<'nvml>,
+    pub event_type: EventTypes,
+    /// The raw `eventData` NVML attached to the event.
+    pub data: u64,
+}
+
+impl Event<'_> {
+    /// The Xid of a critical Xid error.
+    pub fn xid(&self) -> Option<u64> {
+        if self.event_type.contains(EventTypes::XID_CRITICAL_ERROR) {
+            Some(self.data)
+        } else {
+            None
+        }
+    }
+}
+
+/// An RAII wrapper around `nvmlEventSet_t`; the set is freed on drop.
+pub struct EventSet<'nvml> {
+    raw: RawEventSet,
+    // Registered devices by handle, to give events back a `Handler`.
+    devices: HashMap<usize, Handler<'nvml>>,
+}
+
+impl<'nvml> EventSet<'nvml> {
+    pub fn new(nvml: &'nvml NVML) -> Result<Self> {
+        unsafe {
+            let mut set: nvmlEventSet_t = std::ptr::null_mut();
+            let result = nvml
+                .backend
+                .nvmlEventSetCreate(&mut set as *mut nvmlEventSet_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlEventSetCreate"));
+            }
+            Ok(EventSet {
+                raw: RawEventSet {
+                    set,
+                    backend: nvml.backend.clone(),
+                },
+                devices: HashMap::new(),
+            })
+        }
+    }
+
+    /// Registers the `event_types` the device supports and returns the subset it
+    /// does not support, which is left unregistered.
+    pub fn register(
+        &mut self,
+        handler: &Handler<'nvml>,
+        event_types: EventTypes,
+    ) -> Result<EventTypes> {
+        let supported = handler.get_supported_event_types()?;
+        let registered = event_types & supported;
+        if !registered.is_empty() {
+            unsafe {
+                let result = self.raw.backend.nvmlDeviceRegisterEvents(
+                    handler.dev,
+                    registered.bits() as c_ulonglong,
+                    self.raw.set,
+                );
+                if result != nvmlReturn_enum_NVML_SUCCESS {
+                    return Err(Error::nvml(result, "nvmlDeviceRegisterEvents")
+                        .with_device_index(handler.index()));
+                }
+            }
+            self.devices.insert(handler.dev as usize, handler.clone());
+        }
+        Ok(event_types - registered)
+    }
+
+    /// Blocks until an event arrives or `timeout` passes, in which case the error
+    /// is of kind [`ErrorKind::Timeout`].
+    pub fn wait(&self, timeout: Duration) -> Result<Event<'nvml>> {
+        resolve(&self.devices, self.raw.wait(timeout))
+    }
+
+    /// Moves the set to a background thread that forwards every event into a
+    /// channel. The thread polls with `poll_interval` so it notices when the
+    /// listener is stopped.
+    pub fn listen(self, poll_interval: Duration) -> EventListener<'nvml> {
+        let EventSet { raw, devices } = self;
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let stop = stop.clone();
+            std::thread::spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let event = match raw.wait(poll_interval) {
+                        Err(ref e) if e.kind() == ErrorKind::Timeout => continue,
+                        event => event,
+                    };
+                    let failed = event.is_err();
+                    if sender.send(event).is_err() || failed {
+                        break;
+                    }
+                }
+                raw
+            })
+        };
+        EventListener {
+            receiver,
+            stop,
+            thread: Some(thread),
+            devices,
+        }
+    }
+}
+
+// The NVML side of an event set. It holds no device handles, so a listener
+// thread can own it without borrowing NVML.
+struct RawEventSet {
+    set: nvmlEventSet_t,
+    backend: Arc<dyn Backend>,
+}
+
+// NVML is thread-safe; an event set may be waited on from another thread.
+unsafe impl Send for RawEventSet {}
+
+impl RawEventSet {
+    fn wait(&self, timeout: Duration) -> Result<RawEvent> {
+        unsafe {
+            let mut data: nvmlEventData_t = std::mem::zeroed();
+            let result = self.backend.nvmlEventSetWait(
+                self.set,
+                &mut data as *mut nvmlEventData_t,
+                timeout.as_millis().min(c_uint::MAX as u128) as c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlEventSetWait"));
+            }
+            Ok(RawEvent {
+                device: data.device as usize,
+                event_type: data.eventType,
+                data: data.eventData,
+            })
+        }
+    }
+}
+
+impl Drop for RawEventSet {
+    fn drop(&mut self) {
+        unsafe {
+            self.backend.nvmlEventSetFree(self.set);
+        }
+    }
+}
+
+// An event before its device handle is resolved to a registered `Handler`.
+struct RawEvent {
+    device: usize,
+    event_type: u64,
+    data: u64,
+}
+
+fn resolve<'nvml>(
+    devices: &HashMap<usize, Handler<'nvml>>,
+    event: Result<RawEvent>,
+) -> Result<Event<'nvml>> {
+    let event = event?;
+    let device = match devices.get(&event.device) {
+        Some(device) => device.clone(),
+        None => return Err(Error::new("event reported for an unregistered device")),
+    };
+    Ok(Event {
+        device,
+        event_type: EventTypes::from_bits_retain(event.event_type),
+        data: event.data,
+    })
+}
+
+/// The receiving end of [`EventSet::listen`].
+///
+/// The channel yields events until the listener is stopped; an error other than a
+/// timeout is forwarded once and ends the background thread.
+pub struct EventListener<'nvml> {
+    receiver: Receiver<Result<RawEvent>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<RawEventSet>>,
+    devices: HashMap<usize, Handler<'nvml>>,
+}
+
+impl<'nvml> EventListener<'nvml> {
+    pub fn recv(&self) -> Option<Result<Event<'nvml>>> {
+        let event = self.receiver.recv().ok()?;
+        Some(resolve(&self.devices, event))
+    }
+
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<Result<Event<'nvml>>> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(event) => Some(resolve(&self.devices, event)),
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => None,
+        }
+    }
+
+    pub fn try_recv(&self) -> Option<Result<Event<'nvml>>> {
+        match self.receiver.try_recv() {
+            Ok(event) => Some(resolve(&self.devices, event)),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Disconnected) => None,
+        }
+    }
+
+    /// Stops the background thread and hands the event set back. Fails if the
+    /// thread panicked, in which case the set was freed as it unwound.
+    pub fn stop(mut self) -> Result<EventSet<'nvml>> {
+        self.stop.store(true, Ordering::Relaxed);
+        let thread = self.thread.take().expect("the thread is joined only once");
+        let raw = thread
+            .join()
+            .map_err(|_| Error::new("event listener thread panicked"))?;
+        Ok(EventSet {
+            raw,
+            devices: std::mem::take(&mut self.devices),
+        })
+    }
+}
+
+impl Drop for EventListener<'_> {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+
+    #[test]
+    fn events_are_delivered_to_registered_sets() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            supported_event_types: (EventTypes::XID_CRITICAL_ERROR | EventTypes::CLOCK).bits(),
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let handler = Handler::new(&nvml, 0).unwrap();
+        let mut set = EventSet::new(&nvml).unwrap();
+        let unsupported = set
+            .register(
+                &handler,
+                EventTypes::XID_CRITICAL_ERROR | EventTypes::PSTATE,
+            )
+            .unwrap();
+        assert_eq!(unsupported, EventTypes::PSTATE);
+
+        let error = set.wait(Duration::from_millis(1)).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::Timeout);
+
+        backend.emit_event(0, EventTypes::XID_CRITICAL_ERROR.bits(), 79);
+        let event = set.wait(Duration::from_millis(1)).unwrap();
+        assert_eq!(event.device.index(), 0);
+        assert_eq!(event.xid(), Some(79));
+
+        let listener = set.listen(Duration::from_millis(10));
+        backend.emit_event(0, EventTypes::XID_CRITICAL_ERROR.bits(), 48);
+        let event = listener.recv().unwrap().unwrap();
+        assert_eq!(event.xid(), Some(48));
+        let set = listener.stop().unwrap();
+        backend.emit_event(0, EventTypes::XID_CRITICAL_ERROR.bits(), 13);
+        assert_eq!(set.wait(Duration::from_millis(1)).unwrap().xid(), Some(13));
+    }
+}
@@ -0,0 +1,298 @@
+//! Batch queries of device fields with `nvmlDeviceGetFieldValues`.
+//!
+//! One call returns any number of [`FieldId`]s, each with its own status, which is
+//! much cheaper than one call per metric when polling many GPUs.
+
+use std::os::raw::c_int;
+use std::time::Duration;
+
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::sample::SampleValue;
+use crate::{Device, Handler, NVML};
+
+// Declares `FieldId` with one variant per `NVML_FI_DEV_*` constant, along with
+// `FieldId::ALL` and the conversions from and to the raw id.
+macro_rules! field_ids {
+    ($($(#[$doc:meta])* $variant:ident = $raw:ident,)*) => {
+        /// A field of `nvmlDeviceGetFieldValues`, one of the `NVML_FI_DEV_*` ids.
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum FieldId {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl FieldId {
+            pub const ALL: [FieldId; [$(FieldId::$variant,)*].len()] = [$(FieldId::$variant,)*];
+
+            /// `None` for ids added after the vendored `nvml.h`.
+            pub fn from_raw(id: u32) -> Option<FieldId> {
+                match id {
+                    $($raw => Some(FieldId::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// The name of the constant in `nvml.h`, such as `NVML_FI_DEV_ECC_CURRENT`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(FieldId::$variant => stringify!($raw),)*
+                }
+            }
+        }
+
+        impl From<FieldId> for u32 {
+            fn from(field: FieldId) -> u32 {
+                match field {
+                    $(FieldId::$variant => $raw,)*
+                }
+            }
+        }
+    };
+}
+
+field_ids! {
+    /// Whether ECC is enabled.
+    EccCurrent = NVML_FI_DEV_ECC_CURRENT,
+    /// Whether ECC is enabled after the next reboot.
+    EccPending = NVML_FI_DEV_ECC_PENDING,
+    /// Single bit ECC errors since the driver loaded.
+    EccSbeVolTotal = NVML_FI_DEV_ECC_SBE_VOL_TOTAL,
+    /// Double bit ECC errors since the driver loaded.
+    EccDbeVolTotal = NVML_FI_DEV_ECC_DBE_VOL_TOTAL,
+    /// Single bit ECC errors over the lifetime of the device.
+    EccSbeAggTotal = NVML_FI_DEV_ECC_SBE_AGG_TOTAL,
+    /// Double bit ECC errors over the lifetime of the device.
+    EccDbeAggTotal = NVML_FI_DEV_ECC_DBE_AGG_TOTAL,
+    EccSbeVolL1 = NVML_FI_DEV_ECC_SBE_VOL_L1,
+    EccDbeVolL1 = NVML_FI_DEV_ECC_DBE_VOL_L1,
+    EccSbeVolL2 = NVML_FI_DEV_ECC_SBE_VOL_L2,
+    EccDbeVolL2 = NVML_FI_DEV_ECC_DBE_VOL_L2,
+    EccSbeVolDev = NVML_FI_DEV_ECC_SBE_VOL_DEV,
+    EccDbeVolDev = NVML_FI_DEV_ECC_DBE_VOL_DEV,
+    EccSbeVolReg = NVML_FI_DEV_ECC_SBE_VOL_REG,
+    EccDbeVolReg = NVML_FI_DEV_ECC_DBE_VOL_REG,
+    EccSbeVolTex = NVML_FI_DEV_ECC_SBE_VOL_TEX,
+    EccDbeVolTex = NVML_FI_DEV_ECC_DBE_VOL_TEX,
+    EccDbeVolCbu = NVML_FI_DEV_ECC_DBE_VOL_CBU,
+    EccSbeAggL1 = NVML_FI_DEV_ECC_SBE_AGG_L1,
+    EccDbeAggL1 = NVML_FI_DEV_ECC_DBE_AGG_L1,
+    EccSbeAggL2 = NVML_FI_DEV_ECC_SBE_AGG_L2,
+    EccDbeAggL2 = NVML_FI_DEV_ECC_DBE_AGG_L2,
+    EccSbeAggDev = NVML_FI_DEV_ECC_SBE_AGG_DEV,
+    EccDbeAggDev = NVML_FI_DEV_ECC_DBE_AGG_DEV,
+    EccSbeAggReg = NVML_FI_DEV_ECC_SBE_AGG_REG,
+    EccDbeAggReg = NVML_FI_DEV_ECC_DBE_AGG_REG,
+    EccSbeAggTex = NVML_FI_DEV_ECC_SBE_AGG_TEX,
+    EccDbeAggTex = NVML_FI_DEV_ECC_DBE_AGG_TEX,
+    EccDbeAggCbu = NVML_FI_DEV_ECC_DBE_AGG_CBU,
+    /// Pages retired for multiple single bit ECC errors.
+    RetiredSbe = NVML_FI_DEV_RETIRED_SBE,
+    /// Pages retired for a double bit ECC error.
+    RetiredDbe = NVML_FI_DEV_RETIRED_DBE,
+    /// Whether pages are waiting for a reboot to be retired.
+    RetiredPending = NVML_FI_DEV_RETIRED_PENDING,
+    NvLinkCrcFlitErrorCountL0 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L0,
+    NvLinkCrcFlitErrorCountL1 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L1,
+    NvLinkCrcFlitErrorCountL2 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L2,
+    NvLinkCrcFlitErrorCountL3 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L3,
+    NvLinkCrcFlitErrorCountL4 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L4,
+    NvLinkCrcFlitErrorCountL5 = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_L5,
+    /// NvLink flow control CRC errors summed over every link.
+    NvLinkCrcFlitErrorCountTotal = NVML_FI_DEV_NVLINK_CRC_FLIT_ERROR_COUNT_TOTAL,
+    NvLinkCrcDataErrorCountL0 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L0,
+    NvLinkCrcDataErrorCountL1 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L1,
+    NvLinkCrcDataErrorCountL2 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L2,
+    NvLinkCrcDataErrorCountL3 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L3,
+    NvLinkCrcDataErrorCountL4 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L4,
+    NvLinkCrcDataErrorCountL5 = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_L5,
+    /// NvLink data CRC errors summed over every link.
+    NvLinkCrcDataErrorCountTotal = NVML_FI_DEV_NVLINK_CRC_DATA_ERROR_COUNT_TOTAL,
+    NvLinkReplayErrorCountL0 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L0,
+    NvLinkReplayErrorCountL1 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L1,
+    NvLinkReplayErrorCountL2 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L2,
+    NvLinkReplayErrorCountL3 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L3,
+    NvLinkReplayErrorCountL4 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L4,
+    NvLinkReplayErrorCountL5 = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_L5,
+    /// NvLink replay errors summed over every link.
+    NvLinkReplayErrorCountTotal = NVML_FI_DEV_NVLINK_REPLAY_ERROR_COUNT_TOTAL,
+    NvLinkRecoveryErrorCountL0 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L0,
+    NvLinkRecoveryErrorCountL1 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L1,
+    NvLinkRecoveryErrorCountL2 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L2,
+    NvLinkRecoveryErrorCountL3 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L3,
+    NvLinkRecoveryErrorCountL4 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L4,
+    NvLinkRecoveryErrorCountL5 = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_L5,
+    /// NvLink recovery errors summed over every link.
+    NvLinkRecoveryErrorCountTotal = NVML_FI_DEV_NVLINK_RECOVERY_ERROR_COUNT_TOTAL,
+    NvLinkBandwidthC0L0 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L0,
+    NvLinkBandwidthC0L1 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L1,
+    NvLinkBandwidthC0L2 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L2,
+    NvLinkBandwidthC0L3 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L3,
+    NvLinkBandwidthC0L4 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L4,
+    NvLinkBandwidthC0L5 = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_L5,
+    /// NvLink utilization counter 0 summed over every link.
+    NvLinkBandwidthC0Total = NVML_FI_DEV_NVLINK_BANDWIDTH_C0_TOTAL,
+    NvLinkBandwidthC1L0 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L0,
+    NvLinkBandwidthC1L1 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L1,
+    NvLinkBandwidthC1L2 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L2,
+    NvLinkBandwidthC1L3 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L3,
+    NvLinkBandwidthC1L4 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L4,
+    NvLinkBandwidthC1L5 = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_L5,
+    /// NvLink utilization counter 1 summed over every link.
+    NvLinkBandwidthC1Total = NVML_FI_DEV_NVLINK_BANDWIDTH_C1_TOTAL,
+    /// Time the clocks were held back by the power limit; `nvml.h` gives no unit.
+    PerfPolicyPower = NVML_FI_DEV_PERF_POLICY_POWER,
+    /// Time the clocks were held back by the thermal limit; `nvml.h` gives no unit.
+    PerfPolicyThermal = NVML_FI_DEV_PERF_POLICY_THERMAL,
+    PerfPolicySyncBoost = NVML_FI_DEV_PERF_POLICY_SYNC_BOOST,
+    PerfPolicyBoardLimit = NVML_FI_DEV_PERF_POLICY_BOARD_LIMIT,
+    PerfPolicyLowUtilization = NVML_FI_DEV_PERF_POLICY_LOW_UTILIZATION,
+    PerfPolicyReliability = NVML_FI_DEV_PERF_POLICY_RELIABILITY,
+    PerfPolicyTotalAppClocks = NVML_FI_DEV_PERF_POLICY_TOTAL_APP_CLOCKS,
+    PerfPolicyTotalBaseClocks = NVML_FI_DEV_PERF_POLICY_TOTAL_BASE_CLOCKS,
+    /// Memory temperature in degrees C.
+    MemoryTemp = NVML_FI_DEV_MEMORY_TEMP,
+    /// Energy consumed since the driver loaded, in millijoules.
+    TotalEnergyConsumption = NVML_FI_DEV_TOTAL_ENERGY_CONSUMPTION,
+    NvLinkSpeedMbpsL0 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L0,
+    NvLinkSpeedMbpsL1 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L1,
+    NvLinkSpeedMbpsL2 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L2,
+    NvLinkSpeedMbpsL3 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L3,
+    NvLinkSpeedMbpsL4 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L4,
+    NvLinkSpeedMbpsL5 = NVML_FI_DEV_NVLINK_SPEED_MBPS_L5,
+    /// The speed all NvLinks run at, in MB/s.
+    NvLinkSpeedMbpsCommon = NVML_FI_DEV_NVLINK_SPEED_MBPS_COMMON,
+    /// The number of NvLinks on the device.
+    NvLinkLinkCount = NVML_FI_DEV_NVLINK_LINK_COUNT,
+    RetiredPendingSbe = NVML_FI_DEV_RETIRED_PENDING_SBE,
+    RetiredPendingDbe = NVML_FI_DEV_RETIRED_PENDING_DBE,
+    /// PCIe replays.
+    PcieReplayCounter = NVML_FI_DEV_PCIE_REPLAY_COUNTER,
+    /// Times the PCIe replay counter rolled over.
+    PcieReplayRolloverCounter = NVML_FI_DEV_PCIE_REPLAY_ROLLOVER_COUNTER,
+}
+
+/// The outcome of one field of a batch query.
+#[derive(Debug)]
+pub struct FieldValue {
+    pub field: FieldId,
+    /// CPU timestamp of the value in microseconds since 1970.
+    pub timestamp: i64,
+    /// How long NVML took to update the value; fields served by the same driver
+    /// call share an average.
+    pub latency: Duration,
+    /// Fails on its own, for example with `NotSupported`, without failing the
+    /// other fields.
+    pub value: Result<SampleValue>,
+}
+
+impl Handler<'_> {
+    /// Reads `fields` with a single `nvmlDeviceGetFieldValues` call. The values
+    /// come back in the order of `fields`.
+    pub fn get_field_values(&self, fields: &[FieldId]) -> Result<Vec<FieldValue>> {
+        if fields.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut values: Vec<nvmlFieldValue_t> = fields
+            .iter()
+            .map(|field| {
+                let mut value: nvmlFieldValue_t = unsafe { std::mem::zeroed() };
+                value.fieldId = u32::from(*field);
+                value
+            })
+            .collect();
+        unsafe {
+            let result = self.backend.nvmlDeviceGetFieldValues(
+                self.dev,
+                values.len() as c_int,
+                values.as_mut_ptr(),
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetFieldValues"));
+            }
+        }
+        Ok(fields
+            .iter()
+            .zip(values.iter())
+            .map(|(field, value)| FieldValue {
+                field: *field,
+                timestamp: value.timestamp,
+                latency: Duration::from_micros(value.latencyUsec.max(0) as u64),
+                value: self.field_value(value),
+            })
+            .collect())
+    }
+
+    fn field_value(&self, value: &nvmlFieldValue_t) -> Result<SampleValue> {
+        if value.nvmlReturn != nvmlReturn_enum_NVML_SUCCESS {
+            return Err(self.error(value.nvmlReturn, "nvmlDeviceGetFieldValues"));
+        }
+        unsafe { SampleValue::from_raw(value.valueType, &value.value) }.ok_or_else(|| {
+            self.error(
+                nvmlReturn_enum_NVML_ERROR_UNKNOWN,
+                "nvmlDeviceGetFieldValues",
+            )
+        })
+    }
+}
+
+impl Device<'_> {
+    /// Reads `fields` with a single driver call; see
+    /// [`Handler::get_field_values`].
+    pub fn field_values(&self, fields: &[FieldId]) -> Result<Vec<FieldValue>> {
+        self.handler.get_field_values(fields)
+    }
+}
+
+impl NVML {
+    /// Reads `fields` on every device, with one driver call per device, indexed
+    /// by device index.
+    pub fn field_values(&self, fields: &[FieldId]) -> Result<Vec<Vec<FieldValue>>> {
+        (0..self.device_count()?)
+            .map(|index| Handler::new(self, index)?.get_field_values(fields))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::simulated::SimulatedBackend;
+    use std::sync::Arc;
+
+    #[test]
+    fn field_values_are_read_in_one_batch() {
+        use crate::sample::SampleValue;
+
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::with_gpus(2))).unwrap();
+        let fields = [
+            FieldId::EccCurrent,
+            FieldId::TotalEnergyConsumption,
+            FieldId::MemoryTemp,
+        ];
+        let values = nvml.field_values(&fields).unwrap();
+        assert_eq!(values.len(), 2);
+        let values = &values[1];
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[0].field, FieldId::EccCurrent);
+        assert_eq!(
+            values[0].value.as_ref().unwrap(),
+            &SampleValue::UnsignedInt(1)
+        );
+        assert_eq!(
+            values[1].value.as_ref().unwrap(),
+            &SampleValue::UnsignedLongLong(0)
+        );
+        assert!(values[1].timestamp > 0);
+        let error = values[2].value.as_ref().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotSupported);
+        assert_eq!(error.device_index(), Some(1));
+
+        assert_eq!(FieldId::ALL.len(), NVML_FI_MAX as usize - 1);
+        for field in FieldId::ALL.iter() {
+            assert_eq!(FieldId::from_raw(u32::from(*field)), Some(*field));
+        }
+        assert_eq!(FieldId::MemoryTemp.name(), "NVML_FI_DEV_MEMORY_TEMP");
+    }
+}
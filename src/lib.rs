@@ -1,54 +1,161 @@
-#[link(name = "nvidia-ml")]
-use ::std::os::raw::{c_char, c_int, c_uchar, c_uint};
+// Matching on the bindgen constants of nvml-binding, e.g. `nvmlReturn_enum_NVML_SUCCESS`.
+#![allow(non_upper_case_globals)]
+
+use ::std::marker::PhantomData;
+use ::std::os::raw::c_uint;
+use ::std::sync::{Arc, OnceLock};
 use nvml_binding::*;
 
+pub mod accounting;
+pub mod admin;
+pub mod affinity;
+pub mod clock;
+pub mod ecc;
+pub mod encoder;
 pub mod error;
+pub mod event;
+pub mod field;
+mod library;
+pub mod migration;
+pub mod nvlink;
+pub mod power;
+pub mod process;
+pub mod retired;
+pub mod sample;
+pub mod selector;
+pub mod simulated;
+pub mod throttle;
+pub mod topology;
 pub mod unit;
+pub mod utilization;
+pub mod vgpu;
+
+pub use nvml_binding::Backend;
+
+use error::{not_supported_as_none, Error, Result};
+
+// Backend methods that take a device and fill in a single unsigned integer.
+type ProcessOneInterger =
+    unsafe fn(&(dyn Backend + 'static), nvmlDevice_t, *mut c_uint) -> nvmlReturn_t;
+
+// Runs NVML's two-call sizing protocol for list queries: ask for the number of
+// entries with an empty buffer, then fill a buffer of that size, growing it again if
+// entries appeared in between. Failures, including a sizing answer that does not
+// grow the buffer, are returned as the raw return code so the caller can attach its
+// context.
+pub(crate) unsafe fn query_list<T, F>(mut query: F) -> std::result::Result<Vec<T>, nvmlReturn_t>
+where
+    T: Copy,
+    F: FnMut(*mut c_uint, *mut T) -> nvmlReturn_t,
+{
+    let mut buffer: Vec<T> = Vec::new();
+    let mut count: c_uint = 0;
+    loop {
+        let ptr = if buffer.capacity() == 0 {
+            std::ptr::null_mut()
+        } else {
+            buffer.as_mut_ptr()
+        };
+        match query(&mut count as *mut c_uint, ptr) {
+            // Some queries answer the sizing call with success instead of
+            // NVML_ERROR_INSUFFICIENT_SIZE.
+            nvmlReturn_enum_NVML_SUCCESS if ptr.is_null() && count > 0 => {
+                buffer.reserve(count as usize);
+                count = buffer.capacity() as c_uint;
+            }
+            nvmlReturn_enum_NVML_SUCCESS => {
+                // Never trust the driver with more entries than it was given room for.
+                buffer.set_len((count as usize).min(buffer.capacity()));
+                return Ok(buffer);
+            }
+            // A count that does not outgrow the buffer would loop forever.
+            result @ nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE
+                if count as usize <= buffer.capacity() =>
+            {
+                return Err(result)
+            }
+            nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE => {
+                buffer.reserve(count as usize);
+                count = buffer.capacity() as c_uint;
+            }
+            result => return Err(result),
+        }
+    }
+}
 
-use error::{Error, Result};
-
-// If you want to use the function of c as the callback function of rust,
-// the function type must be marked as unsafe extern "C"
-type ProcessOneInterger = unsafe extern "C" fn(*mut nvmlDevice_st, *mut u32) -> u32;
-
-pub struct NVML;
+pub struct NVML {
+    pub(crate) backend: Arc<dyn Backend>,
+}
 
 impl NVML {
+    /// Loads libnvidia-ml and initializes it.
+    ///
+    /// Fails with a library error instead of refusing to start when the NVIDIA
+    /// driver is not installed.
     pub fn new() -> Result<NVML> {
+        let lib: &'static NvmlLib = library::load()?;
+        Self::with_backend(Arc::new(lib))
+    }
+
+    /// Initializes NVML on top of `backend`, such as a
+    /// [`SimulatedBackend`](simulated::SimulatedBackend) in tests.
+    pub fn with_backend(backend: Arc<dyn Backend>) -> Result<NVML> {
         unsafe {
-            let result = nvmlInit_v2();
+            let result = backend.nvmlInit_v2();
             if result == nvmlReturn_enum_NVML_SUCCESS {
-                return Ok(NVML);
+                return Ok(NVML { backend });
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlInit_v2"))
         }
     }
 
     pub fn device_count(&self) -> Result<u32> {
         unsafe {
             let mut n: ::std::os::raw::c_uint = 0;
-            let result = nvmlDeviceGetCount_v2(&mut n as *mut u32);
+            let result = self.backend.nvmlDeviceGetCount_v2(&mut n as *mut u32);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(n as u32);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlDeviceGetCount_v2"))
         }
     }
 
+    /// The device at `index`, borrowing `self` so that it cannot be used after
+    /// NVML shuts down:
+    ///
+    /// ```compile_fail
+    /// let nvml = nvml_rs::NVML::new().unwrap();
+    /// let device = nvml.device_by_index(0).unwrap();
+    /// drop(nvml);
+    /// device.power_usage().unwrap();
+    /// ```
+    pub fn device_by_index(&self, index: u32) -> Result<Device<'_>> {
+        Device::new(self, index)
+    }
+
+    /// Every device, in index order.
+    pub fn devices(&self) -> Result<Devices<'_>> {
+        Ok(Devices {
+            nvml: self,
+            indices: 0..self.device_count()?,
+        })
+    }
+
     pub fn driver_version(&self) -> Result<String> {
         unsafe {
             let mut driver: [::std::os::raw::c_char;
                 NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE as usize] =
                 [0; NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE as usize];
-            let result =
-                nvmlSystemGetDriverVersion(&mut driver[0], NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE);
+            let result = self
+                .backend
+                .nvmlSystemGetDriverVersion(&mut driver[0], NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(std::ffi::CStr::from_ptr(driver.as_ptr() as *const _)
                     .to_str()
                     .unwrap()
                     .to_owned());
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlSystemGetDriverVersion"))
         }
     }
 
@@ -57,51 +164,77 @@ impl NVML {
             let mut driver: [::std::os::raw::c_char;
                 NVML_SYSTEM_NVML_VERSION_BUFFER_SIZE as usize] =
                 [0; NVML_SYSTEM_NVML_VERSION_BUFFER_SIZE as usize];
-            let result =
-                nvmlSystemGetDriverVersion(&mut driver[0], NVML_SYSTEM_NVML_VERSION_BUFFER_SIZE);
+            let result = self
+                .backend
+                .nvmlSystemGetDriverVersion(&mut driver[0], NVML_SYSTEM_NVML_VERSION_BUFFER_SIZE);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(std::ffi::CStr::from_ptr(driver.as_ptr() as *const _)
                     .to_str()
                     .unwrap()
                     .to_owned());
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlSystemGetDriverVersion"))
         }
     }
 
     pub fn cuda_version(&self) -> Result<u64> {
         unsafe {
             let mut version = 0;
-            let result = nvmlSystemGetCudaDriverVersion(&mut version as *mut ::std::os::raw::c_int);
+            let result = self
+                .backend
+                .nvmlSystemGetCudaDriverVersion(&mut version as *mut ::std::os::raw::c_int);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(version as u64);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlSystemGetCudaDriverVersion"))
         }
     }
     pub fn unit_count(&self) -> Result<u64> {
         unsafe {
             let mut unit_count: c_uint = 0;
-            let result = nvmlUnitGetCount(&mut unit_count as *mut c_uint);
+            let result = self
+                .backend
+                .nvmlUnitGetCount(&mut unit_count as *mut c_uint);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(unit_count as u64);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetCount"))
         }
     }
 
-    pub fn unit_handle_by_index(&self, index: u32) -> Result<unit::Unit> {
-        unit::Unit::new(index)
+    pub fn unit_handle_by_index(&self, index: u32) -> Result<unit::Unit<'_>> {
+        unit::Unit::new(self, index)
     }
 }
 
 impl Drop for NVML {
     fn drop(&mut self) {
         unsafe {
-            nvmlShutdown();
+            self.backend.nvmlShutdown();
         }
     }
 }
+
+/// The iterator returned by [`NVML::devices`].
+pub struct Devices<'nvml> {
+    nvml: &'nvml NVML,
+    indices: std::ops::Range<u32>,
+}
+
+impl<'nvml> Iterator for Devices<'nvml> {
+    type Item = Result<Device<'nvml>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next()?;
+        Some(Device::new(self.nvml, index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Devices<'_> {}
 #[derive(Clone)]
 pub struct PCIInfo {
     pub bus_id: String,
@@ -119,26 +252,28 @@ impl Default for PCIInfo {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ClockInfo {
+    /// Current SM clock in MHz.
     pub cores: u64,
+    /// Current memory clock in MHz.
     pub memory: u64,
+    /// Every clock of every domain.
+    pub domains: clock::ClockDomains,
+    /// The memory clocks usable as applications clocks with the graphics clocks
+    /// each pairs with; empty if the device does not report them.
+    pub supported: Vec<clock::SupportedClocks>,
 }
 
-impl Default for ClockInfo {
-    fn default() -> ClockInfo {
-        ClockInfo {
-            cores: 0,
-            memory: 0,
-        }
-    }
-}
-
+/// How the device reaches another GPU.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct P2PLink {
+    /// PCI bus id of the other GPU.
     pub bus_id: String,
     pub link: P2PLinkType,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum P2PLinkType {
     P2PLinkUnknown = 0,
     P2PLinkCrossCPU = 1,
@@ -162,43 +297,35 @@ pub struct CudaComputeCapabilityInfo {
 }
 
 #[derive(Clone)]
-pub struct Device {
-    pub handler: Handler,
+pub struct Device<'nvml> {
+    pub handler: Handler<'nvml>,
     pub uuid: String,
     pub path: String,
     pub model: String,
+    /// Power management limit in milliwatts, not the current draw; see
+    /// [`Device::power_usage`].
     pub power: u64,
     pub memory: u64,
-    pub cpu_affinity: u64,
+    /// The CPUs closest to the device; empty if NVML cannot tell.
+    pub cpu_affinity: affinity::CpuSet,
+    /// The NUMA node of the device, from sysfs; `None` off Linux and on machines
+    /// without NUMA.
+    pub numa_node: Option<u32>,
     pub pci: PCIInfo,
     pub clocks: ClockInfo,
-    pub topology: Vec<i8>,
+    /// How the device reaches every other GPU. It takes a query per GPU, so it is
+    /// filled by the first call to [`Device::p2p_links`].
+    pub topology: OnceLock<Vec<P2PLink>>,
     pub cuda_compute_capability: CudaComputeCapabilityInfo,
 }
 
-impl Default for Device {
-    fn default() -> Device {
-        unsafe {
-            Device {
-                handler: std::mem::uninitialized(),
-                uuid: String::from(""),
-                path: String::from(""),
-                model: String::from(""),
-                power: 0,
-                memory: 0,
-                cpu_affinity: 0,
-                pci: std::mem::uninitialized(),
-                clocks: std::mem::uninitialized(),
-                topology: vec![],
-                cuda_compute_capability: std::mem::uninitialized(),
-            }
-        }
+impl<'nvml> Device<'nvml> {
+    pub fn new(nvml: &'nvml NVML, index: u32) -> Result<Self> {
+        Self::from_handler(Handler::new(nvml, index)?)
     }
-}
 
-impl Device {
-    pub fn new(index: u32) -> Result<Self> {
-        let handler = Handler::new(index)?;
+    /// Reads the static properties of the device `handler` refers to.
+    pub fn from_handler(handler: Handler<'nvml>) -> Result<Self> {
         let model = handler.get_name()?;
         let uuid = handler.get_uuid()?;
         let minor_count = handler.get_minor_number()?;
@@ -208,36 +335,47 @@ impl Device {
         let (bar1, _) = handler.get_bar1_memory_info()?;
         let pcig = handler.get_max_pcie_link_generation()?;
         let pciw = handler.get_max_pcie_link_width()?;
-        let (cores, mem) = handler.get_clock_info()?;
+        let clocks = ClockInfo::new(&handler)?;
         let (major, minor) = handler.get_cuda_compute_capability()?;
-        let node = Self::numa_node(&bus_id)?;
+        let cpu_affinity = not_supported_as_none(handler.get_cpu_affinity())?.unwrap_or_default();
+        let numa_node = Self::numa_node(&bus_id)?;
         Ok(Device {
             handler,
             uuid,
             path: format!("/dev/nvidia{}", minor_count),
             model,
-            power: power,
+            power,
             memory: memory_info.total,
-            cpu_affinity: node,
+            cpu_affinity,
+            numa_node,
             pci: PCIInfo {
                 bus_id,
                 bar1,
                 bandwidth: Self::pci_bandwidth(pcig, pciw),
             },
-            clocks: ClockInfo { cores, memory: mem },
-            topology: vec![],
+            clocks,
+            topology: OnceLock::new(),
             cuda_compute_capability: CudaComputeCapabilityInfo { minor, major },
         })
     }
 
-    fn numa_node(bus_id: &str) -> Result<u64> {
-        let filepath = format!("/sys/bus/pci/devices/{}/numa_node", bus_id.to_lowercase());
-        match std::fs::read_to_string(&filepath) {
-            Ok(content) => match content.parse() {
-                Ok(node) => Ok(node),
-                Err(e) => Err(Error::new(&format!("{}", e))),
-            },
-            Err(_) => Ok(0),
+    fn numa_node(bus_id: &str) -> Result<Option<u32>> {
+        // sysfs uses the legacy 4 digit domain, NVML 8 digits.
+        let mut bus_id = bus_id.to_lowercase();
+        if bus_id.find(':') == Some(8) {
+            bus_id.drain(..4);
+        }
+        let filepath = format!("/sys/bus/pci/devices/{}/numa_node", bus_id);
+        let content = match std::fs::read_to_string(&filepath) {
+            Ok(content) => content,
+            Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::new(&format!("{}: {}", filepath, e))),
+        };
+        // The kernel reports -1 for devices without a node.
+        match content.trim().parse::<i32>() {
+            Ok(node) if node < 0 => Ok(None),
+            Ok(node) => Ok(Some(node as u32)),
+            Err(e) => Err(Error::invalid_input(&format!("{}: {}", filepath, e))),
         }
     }
     fn pci_bandwidth(gen: u64, width: u64) -> u64 {
@@ -254,63 +392,107 @@ impl Device {
     pub fn get_temperature(&self, dst: DeviceSensorType) -> Result<u64> {
         self.handler.get_temperature(dst)
     }
+
+    /// Lists the compute and graphics processes running on the device. A process
+    /// that is both appears once per type.
+    pub fn processes(&self) -> Result<Vec<process::ProcessInfo>> {
+        let mut processes = self.handler.get_compute_running_processes()?;
+        processes.extend(self.handler.get_graphics_running_processes()?);
+        for process in processes.iter_mut() {
+            process.name = process::process_name(&*self.handler.backend, process.pid);
+        }
+        Ok(processes)
+    }
 }
 
-#[derive(Copy, Clone)]
-pub struct Handler {
+/// A device handle, valid for as long as the [`NVML`] it came from is alive.
+#[derive(Clone)]
+pub struct Handler<'nvml> {
     pub dev: nvmlDevice_t,
+    index: u32,
+    backend: Arc<dyn Backend>,
+    // Handles are invalid once nvmlShutdown runs in the drop of NVML.
+    nvml: PhantomData<&'nvml NVML>,
 }
 
-impl Handler {
-    pub fn new(index: u32) -> Result<Handler> {
+// NVML is thread-safe, so device handles can be shared across threads.
+unsafe impl Send for Handler<'_> {}
+unsafe impl Sync for Handler<'_> {}
+
+impl<'nvml> Handler<'nvml> {
+    pub fn new(nvml: &'nvml NVML, index: u32) -> Result<Self> {
+        Self::with_backend(&nvml.backend, index)
+    }
+
+    // The caller ties `'nvml` to the NVML that owns `backend`.
+    pub(crate) fn with_backend(backend: &Arc<dyn Backend>, index: u32) -> Result<Self> {
         unsafe {
             let mut dev: nvmlDevice_t = std::ptr::null_mut();
-            let result = nvmlDeviceGetHandleByIndex_v2(
+            let result = backend.nvmlDeviceGetHandleByIndex_v2(
                 index as ::std::os::raw::c_uint,
                 &mut dev as *mut nvmlDevice_t,
             );
             if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
+                return Err(
+                    Error::nvml(result, "nvmlDeviceGetHandleByIndex_v2").with_device_index(index)
+                );
             }
-            Ok(Handler { dev })
+            Ok(Handler {
+                dev,
+                index,
+                backend: backend.clone(),
+                nvml: PhantomData,
+            })
         }
     }
+    /// The index of the device, as passed to `nvmlDeviceGetHandleByIndex`.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
     pub fn get_name(&self) -> Result<String> {
         unsafe {
             let mut name: [::std::os::raw::c_char; NVML_DEVICE_NAME_BUFFER_SIZE as usize] =
                 [0; NVML_DEVICE_NAME_BUFFER_SIZE as usize];
-            let result = nvmlDeviceGetName(self.dev, &mut name[0], NVML_DEVICE_NAME_BUFFER_SIZE);
-            if result == nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED {
-                return Err(Error::new("not supported"));
-            }
+            let result = self.backend.nvmlDeviceGetName(
+                self.dev,
+                &mut name[0],
+                NVML_DEVICE_NAME_BUFFER_SIZE,
+            );
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(std::ffi::CStr::from_ptr(name.as_ptr() as *const _)
                     .to_str()
                     .unwrap()
                     .to_owned());
             }
-            Err(result.into())
+            Err(self.error(result, "nvmlDeviceGetName"))
         }
     }
     pub fn get_uuid(&self) -> Result<String> {
         unsafe {
             let mut name: [::std::os::raw::c_char; NVML_DEVICE_UUID_BUFFER_SIZE as usize] =
                 [0; NVML_DEVICE_UUID_BUFFER_SIZE as usize];
-            let result = nvmlDeviceGetUUID(self.dev, &mut name[0], NVML_DEVICE_UUID_BUFFER_SIZE);
+            let result = self.backend.nvmlDeviceGetUUID(
+                self.dev,
+                &mut name[0],
+                NVML_DEVICE_UUID_BUFFER_SIZE,
+            );
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(std::ffi::CStr::from_ptr(name.as_ptr() as *const _)
                     .to_str()
                     .unwrap()
                     .to_owned());
             }
-            Err(result.into())
+            Err(self.error(result, "nvmlDeviceGetUUID"))
         }
     }
 
     pub fn get_pci_info(&self) -> Result<String> {
         unsafe {
-            let mut pci_info: nvmlPciInfo_t = std::mem::uninitialized();
-            let result = nvmlDeviceGetPciInfo_v3(self.dev, &mut pci_info as *mut nvmlPciInfo_t);
+            let mut pci_info: nvmlPciInfo_t = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlDeviceGetPciInfo_v3(self.dev, &mut pci_info as *mut nvmlPciInfo_t);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(
                     std::ffi::CStr::from_ptr(pci_info.busId.as_ptr() as *const _)
@@ -319,108 +501,112 @@ impl Handler {
                         .to_owned(),
                 );
             }
-            Err(result.into())
+            Err(self.error(result, "nvmlDeviceGetPciInfo_v3"))
         }
     }
 
     pub fn get_bar1_memory_info(&self) -> Result<(u64, u64)> {
         unsafe {
-            let mut bar1_memory_info: nvmlBAR1Memory_t = std::mem::uninitialized();
-            let result = nvmlDeviceGetBAR1MemoryInfo(
+            let mut bar1_memory_info: nvmlBAR1Memory_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetBAR1MemoryInfo(
                 self.dev,
                 &mut bar1_memory_info as *mut nvmlBAR1Memory_t,
             );
             if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
+                return Err(self.error(result, "nvmlDeviceGetBAR1MemoryInfo"));
             }
-            return Ok((
+            Ok((
                 bar1_memory_info.bar1Total as u64,
                 bar1_memory_info.bar1Used as u64,
-            ));
+            ))
         }
     }
 
-    pub fn get_clock_info(&self) -> Result<(u64, u64)> {
-        unsafe {
-            let mut sm: ::std::os::raw::c_uint = 0;
-            let mut mem: ::std::os::raw::c_uint = 0;
-            let result = nvmlDeviceGetClockInfo(
-                self.dev,
-                nvmlClockType_enum_NVML_CLOCK_SM,
-                &mut sm as *mut ::std::os::raw::c_uint,
-            );
-
-            if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
-            }
-            let result = nvmlDeviceGetClockInfo(
-                self.dev,
-                nvmlClockType_enum_NVML_CLOCK_MEM,
-                &mut mem as *mut ::std::os::raw::c_uint,
-            );
-            if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
-            }
-            return Ok((sm as u64, mem as u64));
-        }
-    }
     pub fn get_cuda_compute_capability(&self) -> Result<(u64, u64)> {
         unsafe {
             let mut major: ::std::os::raw::c_int = 0;
             let mut minor: ::std::os::raw::c_int = 0;
-            let result = nvmlDeviceGetCudaComputeCapability(
+            let result = self.backend.nvmlDeviceGetCudaComputeCapability(
                 self.dev,
                 &mut major as *mut ::std::os::raw::c_int,
                 &mut minor as *mut ::std::os::raw::c_int,
             );
             if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
+                return Err(self.error(result, "nvmlDeviceGetCudaComputeCapability"));
             }
-            return Ok((major as u64, minor as u64));
+            Ok((major as u64, minor as u64))
         }
     }
 
     pub fn get_memory_info(&self) -> Result<nvmlMemory_t> {
         unsafe {
-            let mut mem: nvmlMemory_t = std::mem::uninitialized();
-            let result = nvmlDeviceGetMemoryInfo(self.dev, &mut mem as *mut nvmlMemory_t);
+            let mut mem: nvmlMemory_t = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlDeviceGetMemoryInfo(self.dev, &mut mem as *mut nvmlMemory_t);
             if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
+                return Err(self.error(result, "nvmlDeviceGetMemoryInfo"));
             }
             Ok(mem)
         }
     }
 
     pub fn get_minor_number(&self) -> Result<u64> {
-        self.get_one_interger(nvmlDeviceGetMinorNumber)
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetMinorNumber,
+            "nvmlDeviceGetMinorNumber",
+        )
     }
 
     pub fn get_power_management_limit(&self) -> Result<u64> {
-        self.get_one_interger(nvmlDeviceGetPowerManagementLimit)
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetPowerManagementLimit,
+            "nvmlDeviceGetPowerManagementLimit",
+        )
     }
 
     pub fn get_max_pcie_link_generation(&self) -> Result<u64> {
-        self.get_one_interger(nvmlDeviceGetMaxPcieLinkGeneration)
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetMaxPcieLinkGeneration,
+            "nvmlDeviceGetMaxPcieLinkGeneration",
+        )
     }
 
     pub fn get_max_pcie_link_width(&self) -> Result<u64> {
-        self.get_one_interger(nvmlDeviceGetMaxPcieLinkWidth)
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetMaxPcieLinkWidth,
+            "nvmlDeviceGetMaxPcieLinkWidth",
+        )
     }
 
     pub fn get_temperature(&self, sensor_type: DeviceSensorType) -> Result<u64> {
         unsafe {
             let mut temperature: c_uint = 0;
-            let result = nvmlDeviceGetTemperature(
+            let result = self.backend.nvmlDeviceGetTemperature(
                 self.dev,
                 sensor_type.into(),
                 &mut temperature as *mut c_uint,
             );
             if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
+                return Err(self.error(result, "nvmlDeviceGetTemperature"));
             }
             Ok(temperature as u64)
         }
     }
+
+    pub fn get_supported_event_types(&self) -> Result<event::EventTypes> {
+        unsafe {
+            let mut event_types: ::std::os::raw::c_ulonglong = 0;
+            let result = self.backend.nvmlDeviceGetSupportedEventTypes(
+                self.dev,
+                &mut event_types as *mut ::std::os::raw::c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetSupportedEventTypes"));
+            }
+            Ok(event::EventTypes::from_bits_truncate(event_types))
+        }
+    }
 }
 
 pub enum DeviceSensorType {
@@ -437,15 +623,122 @@ impl From<DeviceSensorType> for nvmlTemperatureSensors_t {
     }
 }
 
-impl Handler {
-    fn get_one_interger(&self, f: ProcessOneInterger) -> Result<u64> {
+impl Handler<'_> {
+    fn get_one_interger(&self, f: ProcessOneInterger, function: &'static str) -> Result<u64> {
         unsafe {
             let mut n: ::std::os::raw::c_uint = 0;
-            let result = f(self.dev, &mut n as *mut ::std::os::raw::c_uint);
+            let result = f(
+                &*self.backend,
+                self.dev,
+                &mut n as *mut ::std::os::raw::c_uint,
+            );
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(n as u64);
             }
-            Err(result.into())
+            Err(self.error(result, function))
         }
     }
+
+    // Attaches the device this handler refers to to the error returned by `function`.
+    fn error(&self, result: nvmlReturn_t, function: &'static str) -> Error {
+        Error::nvml(result, function).with_device_index(self.index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::simulated::SimulatedBackend;
+
+    #[test]
+    fn query_list_follows_the_sizing_protocol() {
+        let entries = [7u32, 8, 9];
+        let list = unsafe {
+            query_list(|count, buffer: *mut u32| {
+                if buffer.is_null() || (*count as usize) < entries.len() {
+                    *count = entries.len() as c_uint;
+                    return nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE;
+                }
+                std::ptr::copy_nonoverlapping(entries.as_ptr(), buffer, entries.len());
+                *count = entries.len() as c_uint;
+                nvmlReturn_enum_NVML_SUCCESS
+            })
+        };
+        assert_eq!(list.unwrap(), entries);
+    }
+
+    #[test]
+    fn query_list_gives_up_when_the_count_does_not_grow() {
+        let mut calls = 0;
+        let result = unsafe {
+            query_list(|count, _: *mut u32| {
+                calls += 1;
+                *count = 0;
+                nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE
+            })
+        };
+        assert_eq!(result, Err(nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE));
+        assert_eq!(calls, 1);
+
+        let mut calls = 0;
+        let result = unsafe {
+            query_list(|count, _: *mut u32| {
+                calls += 1;
+                *count = 4;
+                nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE
+            })
+        };
+        assert_eq!(result, Err(nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE));
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn query_list_ignores_counts_beyond_the_buffer() {
+        let list = unsafe {
+            query_list(|count, buffer: *mut u32| {
+                if buffer.is_null() {
+                    *count = 2;
+                    return nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE;
+                }
+                for i in 0..*count as usize {
+                    *buffer.add(i) = i as u32;
+                }
+                *count += 100;
+                nvmlReturn_enum_NVML_SUCCESS
+            })
+        };
+        assert!(list.unwrap().len() < 100);
+    }
+
+    #[test]
+    fn invalid_index_is_rejected() {
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::with_gpus(1))).unwrap();
+        let error = Handler::new(&nvml, 1).err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::InvalidArgument);
+        assert_eq!(
+            error.code(),
+            Some(nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT)
+        );
+        assert_eq!(error.function(), Some("nvmlDeviceGetHandleByIndex_v2"));
+        assert_eq!(error.device_index(), Some(1));
+        assert_eq!(
+            error.to_string(),
+            "nvmlDeviceGetHandleByIndex_v2 on device 1: Invalid Argument (code 2)"
+        );
+    }
+
+    #[test]
+    fn devices_are_borrowed_from_nvml() {
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::with_gpus(3))).unwrap();
+        let devices = nvml.devices().unwrap();
+        assert_eq!(devices.len(), 3);
+        let uuids: Vec<String> = devices.map(|device| device.unwrap().uuid).collect();
+        assert_eq!(uuids[1], "GPU-00000001-0000-0000-0000-000000000000");
+        assert_eq!(nvml.device_by_index(2).unwrap().handler.index(), 2);
+        assert_eq!(
+            nvml.device_by_index(3).err().unwrap().kind(),
+            ErrorKind::InvalidArgument
+        );
+    }
 }
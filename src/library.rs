@@ -0,0 +1,18 @@
+use std::sync::OnceLock;
+
+use nvml_binding::NvmlLib;
+
+use crate::error::Result;
+
+// libnvidia-ml is opened once per process and never unloaded: `nvmlShutdown` only
+// tears down NVML's state, and a later `NVML::new` can initialize it again.
+static LIBRARY: OnceLock<NvmlLib> = OnceLock::new();
+
+/// Opens libnvidia-ml unless it is already loaded.
+pub(crate) fn load() -> Result<&'static NvmlLib> {
+    if let Some(lib) = LIBRARY.get() {
+        return Ok(lib);
+    }
+    let lib = NvmlLib::open()?;
+    Ok(LIBRARY.get_or_init(|| lib))
+}
@@ -0,0 +1,575 @@
+//! Whether a vGPU can move to another host.
+//!
+//! The source host reads the [`VgpuMetadata`] of the running instance and the
+//! destination host the [`PgpuMetadata`] of the GPU it would run on. Both are
+//! opaque blobs owned by the driver: send them between hosts with `as_bytes` and
+//! `from_bytes`, then compare them with [`NVML::vgpu_compatibility`].
+
+use std::mem::{offset_of, size_of};
+use std::os::raw::{c_char, c_uint};
+
+use bitflags::bitflags;
+use nvml_binding::*;
+
+use crate::error::{Error, Result};
+use crate::vgpu::VgpuInstance;
+use crate::{Handler, NVML};
+
+/// The metadata of a running vGPU instance, as the source of a migration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VgpuMetadata {
+    bytes: Vec<u8>,
+}
+
+impl VgpuMetadata {
+    /// Reads back the output of [`VgpuMetadata::as_bytes`], usually received from
+    /// another host.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<VgpuMetadata> {
+        validate(
+            &bytes,
+            offset_of!(nvmlVgpuMetadata_t, opaqueDataSize),
+            offset_of!(nvmlVgpuMetadata_t, opaqueData),
+            "vGPU metadata",
+        )?;
+        Ok(VgpuMetadata { bytes })
+    }
+
+    /// The blob exactly as the driver returned it.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// The version of the metadata format.
+    pub fn version(&self) -> u32 {
+        self.header().version
+    }
+
+    pub fn revision(&self) -> u32 {
+        self.header().revision
+    }
+
+    /// The driver version of the guest, `None` until the guest driver has loaded.
+    pub fn guest_driver_version(&self) -> Option<String> {
+        let header = self.header();
+        match header.guestInfoState {
+            nvmlVgpuGuestInfoState_enum_NVML_VGPU_INSTANCE_GUEST_INFO_STATE_INITIALIZED => {
+                Some(string(&header.guestDriverVersion))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn host_driver_version(&self) -> String {
+        string(&self.header().hostDriverVersion)
+    }
+
+    /// The vGPU version the guest driver runs, `None` until it has loaded.
+    pub fn guest_vgpu_version(&self) -> Option<u32> {
+        let header = self.header();
+        match header.guestInfoState {
+            nvmlVgpuGuestInfoState_enum_NVML_VGPU_INSTANCE_GUEST_INFO_STATE_INITIALIZED => {
+                Some(header.guestVgpuVersion)
+            }
+            _ => None,
+        }
+    }
+
+    fn header(&self) -> nvmlVgpuMetadata_t {
+        unsafe { header(&self.bytes) }
+    }
+}
+
+/// The metadata of a physical GPU, as the destination of a migration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PgpuMetadata {
+    bytes: Vec<u8>,
+}
+
+impl PgpuMetadata {
+    /// Reads back the output of [`PgpuMetadata::as_bytes`], usually received from
+    /// another host.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<PgpuMetadata> {
+        validate(
+            &bytes,
+            offset_of!(nvmlVgpuPgpuMetadata_t, opaqueDataSize),
+            offset_of!(nvmlVgpuPgpuMetadata_t, opaqueData),
+            "pGPU metadata",
+        )?;
+        Ok(PgpuMetadata { bytes })
+    }
+
+    /// The blob exactly as the driver returned it.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// The version of the metadata format.
+    pub fn version(&self) -> u32 {
+        self.header().version
+    }
+
+    pub fn revision(&self) -> u32 {
+        self.header().revision
+    }
+
+    pub fn host_driver_version(&self) -> String {
+        string(&self.header().hostDriverVersion)
+    }
+
+    /// Whether the GPU can take part in a migration at all.
+    pub fn migration_supported(&self) -> bool {
+        self.header().pgpuVirtualizationCaps & NVML_VGPU_PGPU_VIRTUALIZATION_CAP_MIGRATION_YES != 0
+    }
+
+    /// The vGPU versions the host driver supports.
+    pub fn host_supported_vgpu_range(&self) -> VgpuVersionRange {
+        self.header().hostSupportedVgpuRange.into()
+    }
+
+    fn header(&self) -> nvmlVgpuPgpuMetadata_t {
+        unsafe { header(&self.bytes) }
+    }
+}
+
+bitflags! {
+    /// The power states of the VM across which the vGPU can move, the
+    /// `NVML_VGPU_VM_COMPATIBILITY_*` bitmask. Empty if it cannot move at all.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct VmCompatibility: u32 {
+        /// The VM is shut down and booted on the destination.
+        const COLD = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_COLD;
+        const HIBERNATE = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_HIBERNATE;
+        const SLEEP = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_SLEEP;
+        /// The VM keeps running.
+        const LIVE = nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_LIVE;
+    }
+}
+
+bitflags! {
+    /// What keeps a vGPU from moving in more VM states, the
+    /// `NVML_VGPU_COMPATIBILITY_LIMIT_*` bitmask.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct CompatibilityLimit: u32 {
+        const HOST_DRIVER =
+            nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_HOST_DRIVER;
+        const GUEST_DRIVER =
+            nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GUEST_DRIVER;
+        const GPU = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GPU;
+        const OTHER = nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_OTHER;
+    }
+}
+
+impl CompatibilityLimit {
+    /// Describes each limit, for telling an operator why a migration is refused.
+    pub fn reasons(self) -> Vec<&'static str> {
+        let mut reasons = vec![];
+        if self.contains(CompatibilityLimit::HOST_DRIVER) {
+            reasons.push("the host driver versions are incompatible");
+        }
+        if self.contains(CompatibilityLimit::GUEST_DRIVER) {
+            reasons.push("the guest driver is not supported by the destination host");
+        }
+        if self.contains(CompatibilityLimit::GPU) {
+            reasons.push("the destination GPU does not support the vGPU");
+        }
+        if self.contains(CompatibilityLimit::OTHER) {
+            reasons.push("limited by an unspecified factor");
+        }
+        reasons
+    }
+}
+
+/// The outcome of [`NVML::vgpu_compatibility`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VgpuCompatibility {
+    pub vm: VmCompatibility,
+    /// Empty when nothing limits the migration.
+    pub limits: CompatibilityLimit,
+}
+
+impl VgpuCompatibility {
+    /// Whether the vGPU can move in at least one VM state.
+    pub fn is_compatible(&self) -> bool {
+        !self.vm.is_empty()
+    }
+
+    /// Whether the vGPU can move while the VM keeps running.
+    pub fn supports_live_migration(&self) -> bool {
+        self.vm.contains(VmCompatibility::LIVE)
+    }
+}
+
+impl From<nvmlVgpuPgpuCompatibility_t> for VgpuCompatibility {
+    fn from(compatibility: nvmlVgpuPgpuCompatibility_t) -> VgpuCompatibility {
+        VgpuCompatibility {
+            vm: VmCompatibility::from_bits_retain(compatibility.vgpuVmCompatibility),
+            limits: CompatibilityLimit::from_bits_retain(compatibility.compatibilityLimitCode),
+        }
+    }
+}
+
+/// An inclusive range of vGPU versions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VgpuVersionRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl VgpuVersionRange {
+    pub fn contains(&self, version: u32) -> bool {
+        self.min <= version && version <= self.max
+    }
+}
+
+impl From<nvmlVgpuVersion_t> for VgpuVersionRange {
+    fn from(version: nvmlVgpuVersion_t) -> VgpuVersionRange {
+        VgpuVersionRange {
+            min: version.minVersion,
+            max: version.maxVersion,
+        }
+    }
+}
+
+impl From<VgpuVersionRange> for nvmlVgpuVersion_t {
+    fn from(range: VgpuVersionRange) -> nvmlVgpuVersion_t {
+        nvmlVgpuVersion_t {
+            minVersion: range.min,
+            maxVersion: range.max,
+        }
+    }
+}
+
+/// The vGPU versions of the host driver, from [`NVML::vgpu_version`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VgpuVersion {
+    /// Every version the driver can run.
+    pub supported: VgpuVersionRange,
+    /// The versions guests may currently use.
+    pub current: VgpuVersionRange,
+}
+
+impl NVML {
+    /// Compares the metadata of a vGPU on the source host with that of a GPU on the
+    /// destination host.
+    pub fn vgpu_compatibility(
+        &self,
+        vgpu: &VgpuMetadata,
+        pgpu: &PgpuMetadata,
+    ) -> Result<VgpuCompatibility> {
+        let mut vgpu = aligned::<nvmlVgpuMetadata_t>(&vgpu.bytes);
+        let mut pgpu = aligned::<nvmlVgpuPgpuMetadata_t>(&pgpu.bytes);
+        unsafe {
+            let mut compatibility: nvmlVgpuPgpuCompatibility_t = std::mem::zeroed();
+            let result = self.backend.nvmlGetVgpuCompatibility(
+                vgpu.as_mut_ptr() as *mut nvmlVgpuMetadata_t,
+                pgpu.as_mut_ptr() as *mut nvmlVgpuPgpuMetadata_t,
+                &mut compatibility as *mut nvmlVgpuPgpuCompatibility_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlGetVgpuCompatibility"));
+            }
+            Ok(compatibility.into())
+        }
+    }
+
+    pub fn vgpu_version(&self) -> Result<VgpuVersion> {
+        unsafe {
+            let mut supported: nvmlVgpuVersion_t = std::mem::zeroed();
+            let mut current: nvmlVgpuVersion_t = std::mem::zeroed();
+            let result = self.backend.nvmlGetVgpuVersion(
+                &mut supported as *mut nvmlVgpuVersion_t,
+                &mut current as *mut nvmlVgpuVersion_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlGetVgpuVersion"));
+            }
+            Ok(VgpuVersion {
+                supported: supported.into(),
+                current: current.into(),
+            })
+        }
+    }
+
+    /// Restricts the vGPU versions guests may use to `range`, which must lie
+    /// within the supported range. Fails while any vGPU is running.
+    pub fn set_vgpu_version(&self, range: VgpuVersionRange) -> Result<()> {
+        unsafe {
+            let mut version: nvmlVgpuVersion_t = range.into();
+            let result = self
+                .backend
+                .nvmlSetVgpuVersion(&mut version as *mut nvmlVgpuVersion_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlSetVgpuVersion"));
+            }
+            Ok(())
+        }
+    }
+}
+
+impl Handler<'_> {
+    /// The metadata of the device as the destination of a migration.
+    pub fn get_vgpu_metadata(&self) -> Result<PgpuMetadata> {
+        let bytes = unsafe {
+            query_blob(|metadata, size| {
+                self.backend
+                    .nvmlDeviceGetVgpuMetadata(self.dev, metadata, size)
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetVgpuMetadata"))?;
+        Ok(PgpuMetadata { bytes })
+    }
+}
+
+impl VgpuInstance<'_> {
+    /// The metadata of the instance as the source of a migration.
+    pub fn metadata(&self) -> Result<VgpuMetadata> {
+        let bytes = unsafe {
+            query_blob(|metadata, size| {
+                self.handler
+                    .backend
+                    .nvmlVgpuInstanceGetMetadata(self.handle, metadata, size)
+            })
+        }
+        .map_err(|result| self.handler.error(result, "nvmlVgpuInstanceGetMetadata"))?;
+        Ok(VgpuMetadata { bytes })
+    }
+}
+
+// Reads a metadata blob whose size the driver reports through `size`, retrying
+// while the blob outgrows the buffer. The buffer is at least as large as `T` and
+// aligned for it.
+unsafe fn query_blob<T, F>(mut query: F) -> std::result::Result<Vec<u8>, nvmlReturn_t>
+where
+    F: FnMut(*mut T, *mut c_uint) -> nvmlReturn_t,
+{
+    let mut size = size_of::<T>() as c_uint;
+    loop {
+        let mut buffer = vec![0u32; words::<T>(size as usize)];
+        let capacity = (buffer.len() * size_of::<u32>()) as c_uint;
+        size = capacity;
+        match query(buffer.as_mut_ptr() as *mut T, &mut size as *mut c_uint) {
+            nvmlReturn_enum_NVML_SUCCESS => {
+                // Never read past the buffer, whatever size the driver reports.
+                let len = size.min(capacity) as usize;
+                let bytes = std::slice::from_raw_parts(buffer.as_ptr() as *const u8, len);
+                return Ok(bytes.to_vec());
+            }
+            nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE if size > capacity => {}
+            result => return Err(result),
+        }
+    }
+}
+
+// Copies a blob into a buffer aligned for `T` and at least as large as `T`, so
+// the driver never reads past its end.
+fn aligned<T>(bytes: &[u8]) -> Vec<u32> {
+    let mut buffer = vec![0u32; words::<T>(bytes.len())];
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), buffer.as_mut_ptr() as *mut u8, bytes.len());
+    }
+    buffer
+}
+
+fn words<T>(bytes: usize) -> usize {
+    bytes.max(size_of::<T>()).div_ceil(size_of::<u32>())
+}
+
+// Every field of the metadata structs is an integer or a byte array, so any
+// prefix of the blob padded with zeros is a valid value.
+unsafe fn header<T>(bytes: &[u8]) -> T {
+    let mut header: T = std::mem::zeroed();
+    std::ptr::copy_nonoverlapping(
+        bytes.as_ptr(),
+        &mut header as *mut T as *mut u8,
+        bytes.len().min(size_of::<T>()),
+    );
+    header
+}
+
+// Checks that the blob holds the fixed fields and the opaque data they announce.
+fn validate(bytes: &[u8], size_offset: usize, data_offset: usize, what: &str) -> Result<()> {
+    let truncated = || Error::invalid_input(&format!("truncated {}: {} bytes", what, bytes.len()));
+    if bytes.len() < data_offset {
+        return Err(truncated());
+    }
+    let mut size = [0; 4];
+    size.copy_from_slice(&bytes[size_offset..size_offset + 4]);
+    if bytes.len() < data_offset + u32::from_ne_bytes(size) as usize {
+        return Err(truncated());
+    }
+    Ok(())
+}
+
+fn string(chars: &[c_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::simulated::{
+        SimulatedBackend, SimulatedDevice, SimulatedVgpuInstance, SimulatedVgpuType,
+    };
+    use crate::Device;
+    use std::sync::Arc;
+
+    #[test]
+    fn query_blob_stays_within_the_buffer() {
+        let bytes = unsafe {
+            query_blob(|_: *mut [u8; 8], size| {
+                *size = 1 << 20;
+                nvmlReturn_enum_NVML_SUCCESS
+            })
+        };
+        assert_eq!(bytes.unwrap().len(), 8);
+    }
+
+    // A vGPU metadata blob whose header announces `opaque` bytes of opaque data.
+    fn vgpu_blob(opaque: u32, len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+        let size = offset_of!(nvmlVgpuMetadata_t, opaqueDataSize);
+        bytes[size..size + 4].copy_from_slice(&opaque.to_ne_bytes());
+        bytes
+    }
+
+    #[test]
+    fn blobs_must_hold_the_data_they_announce() {
+        let data = offset_of!(nvmlVgpuMetadata_t, opaqueData);
+        assert!(VgpuMetadata::from_bytes(vgpu_blob(0, data)).is_ok());
+        assert!(VgpuMetadata::from_bytes(vgpu_blob(16, data + 16)).is_ok());
+        for bytes in [vec![0; 16], vgpu_blob(16, data + 15)] {
+            assert_eq!(
+                VgpuMetadata::from_bytes(bytes).unwrap_err().kind(),
+                ErrorKind::InvalidInput
+            );
+        }
+        assert_eq!(
+            PgpuMetadata::from_bytes(vec![0; 16]).unwrap_err().kind(),
+            ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn header_reads_the_fixed_fields() {
+        let data = offset_of!(nvmlVgpuMetadata_t, opaqueData);
+        let mut bytes = vgpu_blob(0, data);
+        let version = offset_of!(nvmlVgpuMetadata_t, version);
+        bytes[version..version + 4].copy_from_slice(&3u32.to_ne_bytes());
+        let driver = offset_of!(nvmlVgpuMetadata_t, hostDriverVersion);
+        bytes[driver..driver + 6].copy_from_slice(b"418.67");
+
+        let metadata = VgpuMetadata::from_bytes(bytes).unwrap();
+        assert_eq!(metadata.version(), 3);
+        assert_eq!(metadata.host_driver_version(), "418.67");
+        assert_eq!(metadata.guest_driver_version(), None);
+    }
+
+    #[test]
+    fn reasons_follow_the_limits() {
+        assert!(CompatibilityLimit::empty().reasons().is_empty());
+        assert_eq!(
+            (CompatibilityLimit::HOST_DRIVER | CompatibilityLimit::GPU).reasons(),
+            vec![
+                "the host driver versions are incompatible",
+                "the destination GPU does not support the vGPU",
+            ]
+        );
+        assert_eq!(CompatibilityLimit::all().reasons().len(), 4);
+        // Bits newer drivers may set are not described.
+        assert!(CompatibilityLimit::from_bits_retain(1 << 8)
+            .reasons()
+            .is_empty());
+    }
+
+    #[test]
+    fn vgpu_migration_compares_metadata_across_hosts() {
+        let mut source = SimulatedDevice::new(0);
+        source.vgpu_types = vec![SimulatedVgpuType::new(11, "GRID V100-4Q")];
+        source.vgpu_instances = vec![SimulatedVgpuInstance::new(7, 11)];
+        let source = NVML::with_backend(Arc::new(SimulatedBackend::new(vec![source]))).unwrap();
+        let metadata = Device::new(&source, 0).unwrap().vgpu_instances().unwrap()[0]
+            .metadata()
+            .unwrap();
+        assert_eq!(metadata.guest_driver_version().unwrap(), "440.33.01");
+        assert_eq!(metadata.host_driver_version(), "418.67");
+        // Shipped to the destination as bytes.
+        let metadata = VgpuMetadata::from_bytes(metadata.into_bytes()).unwrap();
+
+        let mut destination = SimulatedBackend::new(vec![SimulatedDevice::new(0); 2]);
+        destination.driver_version = "440.33.01".to_owned();
+        destination.update(0, |d| {
+            d.vgpu_types = vec![SimulatedVgpuType::new(11, "GRID V100-4Q")]
+        });
+        destination.update(1, |d| {
+            d.vgpu_types = vec![SimulatedVgpuType::new(11, "GRID V100-4Q")];
+            d.vgpu_migration = false;
+        });
+        let destination = NVML::with_backend(Arc::new(destination)).unwrap();
+        let pgpu = Handler::new(&destination, 0)
+            .unwrap()
+            .get_vgpu_metadata()
+            .unwrap();
+        assert!(pgpu.migration_supported());
+        assert_eq!(
+            pgpu.host_supported_vgpu_range(),
+            VgpuVersionRange {
+                min: 0x0a00,
+                max: 0x0b00
+            }
+        );
+        let pgpu = PgpuMetadata::from_bytes(pgpu.as_bytes().to_vec()).unwrap();
+        let compatibility = destination.vgpu_compatibility(&metadata, &pgpu).unwrap();
+        assert_eq!(compatibility.vm, VmCompatibility::COLD);
+        assert_eq!(compatibility.limits, CompatibilityLimit::HOST_DRIVER);
+        assert!(!compatibility.supports_live_migration());
+        assert_eq!(compatibility.limits.reasons().len(), 1);
+
+        let pgpu = Handler::new(&destination, 1)
+            .unwrap()
+            .get_vgpu_metadata()
+            .unwrap();
+        let compatibility = destination.vgpu_compatibility(&metadata, &pgpu).unwrap();
+        assert!(!compatibility.is_compatible());
+        assert_eq!(compatibility.limits, CompatibilityLimit::GPU);
+
+        let narrowed = VgpuVersionRange {
+            min: 0x0a00,
+            max: 0x0a80,
+        };
+        destination.set_vgpu_version(narrowed).unwrap();
+        assert_eq!(destination.vgpu_version().unwrap().current, narrowed);
+        let pgpu = Handler::new(&destination, 0)
+            .unwrap()
+            .get_vgpu_metadata()
+            .unwrap();
+        let compatibility = destination.vgpu_compatibility(&metadata, &pgpu).unwrap();
+        assert_eq!(compatibility.limits, CompatibilityLimit::GUEST_DRIVER);
+
+        let pgpu = Handler::new(&source, 0)
+            .unwrap()
+            .get_vgpu_metadata()
+            .unwrap();
+        assert!(source
+            .vgpu_compatibility(&metadata, &pgpu)
+            .unwrap()
+            .supports_live_migration());
+        assert_eq!(
+            source.set_vgpu_version(narrowed).unwrap_err().kind(),
+            ErrorKind::InUse
+        );
+    }
+}
@@ -0,0 +1,824 @@
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_uint, c_ulonglong};
+use std::time::{Duration, Instant};
+
+use bitflags::bitflags;
+use nvml_binding::*;
+
+use crate::admin::DeviceAdmin;
+use crate::error::{not_supported_as_none, Error, ErrorKind, Result};
+use crate::{Device, Handler};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NvLinkCapability {
+    P2pSupported,
+    SysmemAccess,
+    P2pAtomics,
+    SysmemAtomics,
+    SliBridge,
+    /// The link is supported by this device.
+    Valid,
+}
+
+impl NvLinkCapability {
+    pub const ALL: [NvLinkCapability; 6] = [
+        NvLinkCapability::P2pSupported,
+        NvLinkCapability::SysmemAccess,
+        NvLinkCapability::P2pAtomics,
+        NvLinkCapability::SysmemAtomics,
+        NvLinkCapability::SliBridge,
+        NvLinkCapability::Valid,
+    ];
+}
+
+impl From<NvLinkCapability> for nvmlNvLinkCapability_t {
+    fn from(capability: NvLinkCapability) -> nvmlNvLinkCapability_t {
+        match capability {
+            NvLinkCapability::P2pSupported => {
+                nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_P2P_SUPPORTED
+            }
+            NvLinkCapability::SysmemAccess => {
+                nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_SYSMEM_ACCESS
+            }
+            NvLinkCapability::P2pAtomics => nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_P2P_ATOMICS,
+            NvLinkCapability::SysmemAtomics => {
+                nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_SYSMEM_ATOMICS
+            }
+            NvLinkCapability::SliBridge => nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_SLI_BRIDGE,
+            NvLinkCapability::Valid => nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_VALID,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NvLinkErrorCounter {
+    /// Data link transmit replays.
+    DlReplay,
+    /// Data link transmit recoveries.
+    DlRecovery,
+    /// Data link receive flow control digit CRC errors.
+    DlCrcFlit,
+    /// Data link receive data CRC errors.
+    DlCrcData,
+}
+
+impl NvLinkErrorCounter {
+    pub const ALL: [NvLinkErrorCounter; 4] = [
+        NvLinkErrorCounter::DlReplay,
+        NvLinkErrorCounter::DlRecovery,
+        NvLinkErrorCounter::DlCrcFlit,
+        NvLinkErrorCounter::DlCrcData,
+    ];
+}
+
+impl From<NvLinkErrorCounter> for nvmlNvLinkErrorCounter_t {
+    fn from(counter: NvLinkErrorCounter) -> nvmlNvLinkErrorCounter_t {
+        match counter {
+            NvLinkErrorCounter::DlReplay => nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_DL_REPLAY,
+            NvLinkErrorCounter::DlRecovery => {
+                nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_DL_RECOVERY
+            }
+            NvLinkErrorCounter::DlCrcFlit => {
+                nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_DL_CRC_FLIT
+            }
+            NvLinkErrorCounter::DlCrcData => {
+                nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_DL_CRC_DATA
+            }
+        }
+    }
+}
+
+/// The far end of an NvLink.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NvLinkRemote {
+    pub bus_id: String,
+    /// The combined 16-bit device id and 16-bit vendor id.
+    pub pci_device_id: u32,
+    /// The index of the local GPU at `bus_id`; `None` for other endpoints, such as
+    /// an NvSwitch or a CPU.
+    pub device_index: Option<u32>,
+}
+
+/// One NvLink of a device. Fields the link does not report are `None` or empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NvLink {
+    pub link: u32,
+    pub active: bool,
+    pub version: Option<u32>,
+    /// The capabilities the link has.
+    pub capabilities: Vec<NvLinkCapability>,
+    pub remote: Option<NvLinkRemote>,
+    pub error_counters: Vec<(NvLinkErrorCounter, u64)>,
+}
+
+impl NvLink {
+    pub fn has_capability(&self, capability: NvLinkCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    pub fn error_counter(&self, counter: NvLinkErrorCounter) -> Option<u64> {
+        self.error_counters
+            .iter()
+            .find(|(c, _)| *c == counter)
+            .map(|(_, value)| *value)
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_nvlink_state(&self, link: u32) -> Result<bool> {
+        unsafe {
+            let mut active: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self.backend.nvmlDeviceGetNvLinkState(
+                self.dev,
+                link as c_uint,
+                &mut active as *mut nvmlEnableState_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkState"));
+            }
+            Ok(active == nvmlEnableState_enum_NVML_FEATURE_ENABLED)
+        }
+    }
+
+    pub fn get_nvlink_version(&self, link: u32) -> Result<u32> {
+        unsafe {
+            let mut version: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetNvLinkVersion(
+                self.dev,
+                link as c_uint,
+                &mut version as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkVersion"));
+            }
+            Ok(version)
+        }
+    }
+
+    pub fn get_nvlink_capability(&self, link: u32, capability: NvLinkCapability) -> Result<bool> {
+        unsafe {
+            let mut cap_result: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetNvLinkCapability(
+                self.dev,
+                link as c_uint,
+                capability.into(),
+                &mut cap_result as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkCapability"));
+            }
+            Ok(cap_result != 0)
+        }
+    }
+
+    pub fn get_nvlink_remote_pci_info(&self, link: u32) -> Result<nvmlPciInfo_t> {
+        unsafe {
+            let mut pci_info: nvmlPciInfo_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetNvLinkRemotePciInfo_v2(
+                self.dev,
+                link as c_uint,
+                &mut pci_info as *mut nvmlPciInfo_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkRemotePciInfo_v2"));
+            }
+            Ok(pci_info)
+        }
+    }
+
+    pub fn get_nvlink_error_counter(&self, link: u32, counter: NvLinkErrorCounter) -> Result<u64> {
+        unsafe {
+            let mut value: c_ulonglong = 0;
+            let result = self.backend.nvmlDeviceGetNvLinkErrorCounter(
+                self.dev,
+                link as c_uint,
+                counter.into(),
+                &mut value as *mut c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkErrorCounter"));
+            }
+            Ok(value)
+        }
+    }
+
+    fn get_nvlink_remote(&self, link: u32) -> Result<Option<NvLinkRemote>> {
+        let pci_info = match not_supported_as_none(self.get_nvlink_remote_pci_info(link))? {
+            Some(pci_info) => pci_info,
+            None => return Ok(None),
+        };
+        let bus_id = unsafe { CStr::from_ptr(pci_info.busId.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+        Ok(Some(NvLinkRemote {
+            device_index: self.local_device_index(&bus_id),
+            bus_id,
+            pci_device_id: pci_info.pciDeviceId,
+        }))
+    }
+
+    // The index of the GPU at `bus_id`, if it is one NVML manages.
+    fn local_device_index(&self, bus_id: &str) -> Option<u32> {
+        let bus_id = CString::new(bus_id).ok()?;
+        unsafe {
+            let mut dev: nvmlDevice_t = std::ptr::null_mut();
+            let result = self
+                .backend
+                .nvmlDeviceGetHandleByPciBusId_v2(bus_id.as_ptr(), &mut dev as *mut nvmlDevice_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return None;
+            }
+            let mut index: c_uint = 0;
+            let result = self
+                .backend
+                .nvmlDeviceGetIndex(dev, &mut index as *mut c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return None;
+            }
+            Some(index)
+        }
+    }
+
+    pub(crate) fn get_nvlinks(&self) -> Result<Vec<NvLink>> {
+        self.get_nvlink_states()?
+            .into_iter()
+            .map(|(link, active)| self.get_nvlink(link, active))
+            .collect()
+    }
+
+    // The links the device has, with whether each is active. Only the state query
+    // tells whether a link exists; failures of other queries belong to a link
+    // that does.
+    fn get_nvlink_states(&self) -> Result<Vec<(u32, bool)>> {
+        let mut states = vec![];
+        for link in 0..NVML_NVLINK_MAX_LINKS {
+            match self.get_nvlink_state(link) {
+                Ok(active) => states.push((link, active)),
+                Err(ref e) if is_missing_link(e) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(states)
+    }
+
+    fn get_nvlink(&self, link: u32, active: bool) -> Result<NvLink> {
+        let mut capabilities = vec![];
+        for capability in NvLinkCapability::ALL.iter() {
+            if not_supported_as_none(self.get_nvlink_capability(link, *capability))? == Some(true) {
+                capabilities.push(*capability);
+            }
+        }
+        let mut error_counters = vec![];
+        for counter in NvLinkErrorCounter::ALL.iter() {
+            if let Some(value) =
+                not_supported_as_none(self.get_nvlink_error_counter(link, *counter))?
+            {
+                error_counters.push((*counter, value));
+            }
+        }
+        Ok(NvLink {
+            link,
+            active,
+            version: not_supported_as_none(self.get_nvlink_version(link))?,
+            capabilities,
+            remote: self.get_nvlink_remote(link)?,
+            error_counters,
+        })
+    }
+}
+
+impl Device<'_> {
+    /// The NvLinks of the device, in link order; empty without NvLink support.
+    pub fn nvlinks(&self) -> Result<Vec<NvLink>> {
+        self.handler.get_nvlinks()
+    }
+}
+
+// `nvmlDeviceGetNvLinkState` rejects links past the last one a device has, and
+// reports every link of a device without NvLink as not supported.
+fn is_missing_link(error: &Error) -> bool {
+    error.kind() == ErrorKind::NotSupported || error.kind() == ErrorKind::InvalidArgument
+}
+
+impl DeviceAdmin<'_> {
+    /// Resets every error counter of `link` to zero.
+    pub fn reset_nvlink_error_counters(&self, link: u32) -> Result<()> {
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceResetNvLinkErrorCounters(self.handler.dev, link as c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlDeviceResetNvLinkErrorCounters"));
+            }
+            Ok(())
+        }
+    }
+}
+
+/// What an NvLink utilization counter counts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NvLinkCounterUnit {
+    Cycles,
+    Packets,
+    Bytes,
+}
+
+impl NvLinkCounterUnit {
+    fn from_raw(units: nvmlNvLinkUtilizationCountUnits_t) -> Option<NvLinkCounterUnit> {
+        match units {
+            nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_CYCLES => {
+                Some(NvLinkCounterUnit::Cycles)
+            }
+            nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_PACKETS => {
+                Some(NvLinkCounterUnit::Packets)
+            }
+            nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_BYTES => {
+                Some(NvLinkCounterUnit::Bytes)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<NvLinkCounterUnit> for nvmlNvLinkUtilizationCountUnits_t {
+    fn from(unit: NvLinkCounterUnit) -> nvmlNvLinkUtilizationCountUnits_t {
+        match unit {
+            NvLinkCounterUnit::Cycles => {
+                nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_CYCLES
+            }
+            NvLinkCounterUnit::Packets => {
+                nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_PACKETS
+            }
+            NvLinkCounterUnit::Bytes => {
+                nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_BYTES
+            }
+        }
+    }
+}
+
+bitflags! {
+    /// The `nvmlNvLinkUtilizationCountPktTypes_t` filter; it only applies to
+    /// counters in packets or bytes.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct NvLinkPacketTypes: u32 {
+        const NOP = nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_NOP;
+        const READ = nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_READ;
+        const WRITE = nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_WRITE;
+        /// Reduction atomic requests.
+        const REDUCTION_ATOMIC =
+            nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_RATOM;
+        /// Non-reduction atomic requests.
+        const NON_REDUCTION_ATOMIC =
+            nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_NRATOM;
+        const FLUSH = nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_FLUSH;
+        /// Responses with data.
+        const RESPONSE_DATA =
+            nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_RESPDATA;
+        /// Responses without data.
+        const RESPONSE_NO_DATA =
+            nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_RESPNODATA;
+    }
+}
+
+/// One of the two utilization counters of a link.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NvLinkCounter {
+    Counter0,
+    Counter1,
+}
+
+impl NvLinkCounter {
+    pub const ALL: [NvLinkCounter; 2] = [NvLinkCounter::Counter0, NvLinkCounter::Counter1];
+}
+
+impl From<NvLinkCounter> for c_uint {
+    fn from(counter: NvLinkCounter) -> c_uint {
+        match counter {
+            NvLinkCounter::Counter0 => 0,
+            NvLinkCounter::Counter1 => 1,
+        }
+    }
+}
+
+/// The configuration of one of the two utilization counters of a link.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NvLinkUtilizationControl {
+    pub units: NvLinkCounterUnit,
+    pub packet_filter: NvLinkPacketTypes,
+}
+
+/// A reading of an NvLink utilization counter, in the units of its control.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NvLinkCounterReading {
+    pub link: u32,
+    pub units: NvLinkCounterUnit,
+    pub rx: u64,
+    pub tx: u64,
+}
+
+/// One utilization counter read on every link at the same time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NvLinkCounterSnapshot {
+    pub taken_at: Instant,
+    pub readings: Vec<NvLinkCounterReading>,
+}
+
+/// The traffic on a link between two [`NvLinkCounterSnapshot`]s, per second in
+/// the units of the counter.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NvLinkThroughput {
+    pub link: u32,
+    pub units: NvLinkCounterUnit,
+    pub rx_per_second: f64,
+    pub tx_per_second: f64,
+}
+
+impl NvLinkCounterSnapshot {
+    /// The rates from this snapshot up to `later`, for the links present in both
+    /// whose counter kept its units. Counters are taken to wrap around.
+    pub fn rates(&self, later: &NvLinkCounterSnapshot) -> Vec<NvLinkThroughput> {
+        let seconds = later
+            .taken_at
+            .saturating_duration_since(self.taken_at)
+            .as_secs_f64();
+        let per_second = |delta: u64| {
+            if seconds > 0.0 {
+                delta as f64 / seconds
+            } else {
+                0.0
+            }
+        };
+        self.readings
+            .iter()
+            .filter_map(|before| {
+                let after = later
+                    .readings
+                    .iter()
+                    .find(|r| r.link == before.link && r.units == before.units)?;
+                Some(NvLinkThroughput {
+                    link: before.link,
+                    units: before.units,
+                    rx_per_second: per_second(after.rx.wrapping_sub(before.rx)),
+                    tx_per_second: per_second(after.tx.wrapping_sub(before.tx)),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_nvlink_utilization_control(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+    ) -> Result<NvLinkUtilizationControl> {
+        unsafe {
+            let mut control: nvmlNvLinkUtilizationControl_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetNvLinkUtilizationControl(
+                self.dev,
+                link as c_uint,
+                counter.into(),
+                &mut control as *mut nvmlNvLinkUtilizationControl_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkUtilizationControl"));
+            }
+            let units = match NvLinkCounterUnit::from_raw(control.units) {
+                Some(units) => units,
+                None => {
+                    return Err(self.error(
+                        nvmlReturn_enum_NVML_ERROR_UNKNOWN,
+                        "nvmlDeviceGetNvLinkUtilizationControl",
+                    ))
+                }
+            };
+            Ok(NvLinkUtilizationControl {
+                units,
+                packet_filter: NvLinkPacketTypes::from_bits_retain(control.pktfilter),
+            })
+        }
+    }
+
+    /// Returns the received and transmitted counts of `counter`.
+    pub fn get_nvlink_utilization_counter(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+    ) -> Result<(u64, u64)> {
+        unsafe {
+            let mut rx: c_ulonglong = 0;
+            let mut tx: c_ulonglong = 0;
+            let result = self.backend.nvmlDeviceGetNvLinkUtilizationCounter(
+                self.dev,
+                link as c_uint,
+                counter.into(),
+                &mut rx as *mut c_ulonglong,
+                &mut tx as *mut c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetNvLinkUtilizationCounter"));
+            }
+            Ok((rx, tx))
+        }
+    }
+
+    fn get_nvlink_counter_reading(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+    ) -> Result<NvLinkCounterReading> {
+        let control = self.get_nvlink_utilization_control(link, counter)?;
+        let (rx, tx) = self.get_nvlink_utilization_counter(link, counter)?;
+        Ok(NvLinkCounterReading {
+            link,
+            units: control.units,
+            rx,
+            tx,
+        })
+    }
+}
+
+impl Device<'_> {
+    /// Reads utilization counter `counter` on every active link.
+    pub fn nvlink_counter_snapshot(&self, counter: NvLinkCounter) -> Result<NvLinkCounterSnapshot> {
+        let mut readings = vec![];
+        for (link, active) in self.handler.get_nvlink_states()? {
+            if active {
+                readings.push(self.handler.get_nvlink_counter_reading(link, counter)?);
+            }
+        }
+        Ok(NvLinkCounterSnapshot {
+            taken_at: Instant::now(),
+            readings,
+        })
+    }
+
+    /// Samples utilization counter `counter` twice, `interval` apart, and
+    /// returns the per-link rates. Blocks for `interval`.
+    pub fn nvlink_throughput(
+        &self,
+        counter: NvLinkCounter,
+        interval: Duration,
+    ) -> Result<Vec<NvLinkThroughput>> {
+        let before = self.nvlink_counter_snapshot(counter)?;
+        std::thread::sleep(interval);
+        let after = self.nvlink_counter_snapshot(counter)?;
+        Ok(before.rates(&after))
+    }
+}
+
+impl DeviceAdmin<'_> {
+    /// Configures utilization counter `counter` of `link`, resetting it first if
+    /// `reset` is set.
+    pub fn set_nvlink_utilization_control(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+        control: NvLinkUtilizationControl,
+        reset: bool,
+    ) -> Result<()> {
+        unsafe {
+            let mut raw = nvmlNvLinkUtilizationControl_t {
+                units: control.units.into(),
+                pktfilter: control.packet_filter.bits(),
+            };
+            let result = self.handler.backend.nvmlDeviceSetNvLinkUtilizationControl(
+                self.handler.dev,
+                link as c_uint,
+                counter.into(),
+                &mut raw as *mut nvmlNvLinkUtilizationControl_t,
+                reset as c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlDeviceSetNvLinkUtilizationControl"));
+            }
+            Ok(())
+        }
+    }
+
+    /// Stops (`freeze`) or resumes counting on utilization counter `counter`.
+    pub fn freeze_nvlink_utilization_counter(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+        freeze: bool,
+    ) -> Result<()> {
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceFreezeNvLinkUtilizationCounter(
+                    self.handler.dev,
+                    link as c_uint,
+                    counter.into(),
+                    if freeze {
+                        nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                    } else {
+                        nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                    },
+                );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlDeviceFreezeNvLinkUtilizationCounter"));
+            }
+            Ok(())
+        }
+    }
+
+    pub fn reset_nvlink_utilization_counter(
+        &self,
+        link: u32,
+        counter: NvLinkCounter,
+    ) -> Result<()> {
+        unsafe {
+            let result = self
+                .handler
+                .backend
+                .nvmlDeviceResetNvLinkUtilizationCounter(
+                    self.handler.dev,
+                    link as c_uint,
+                    counter.into(),
+                );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlDeviceResetNvLinkUtilizationCounter"));
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice, SimulatedNvLink};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn only_the_state_query_decides_a_link_is_missing() {
+        let mut link = SimulatedNvLink::new("00000000:02:00.0");
+        link.version = None;
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            nvlinks: vec![link],
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let handler = Handler::new(&nvml, 0).unwrap();
+        let error = handler.get_nvlinks().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidArgument);
+        assert_eq!(error.function(), Some("nvmlDeviceGetNvLinkVersion"));
+    }
+
+    #[test]
+    fn nvlinks_resolve_their_remote_device() {
+        let mut devices: Vec<SimulatedDevice> = (0..2).map(SimulatedDevice::new).collect();
+        let mut link = SimulatedNvLink::new(&devices[1].pci_bus_id());
+        link.error_counters[nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_DL_REPLAY as usize] = 7;
+        devices[0].nvlinks = vec![link, SimulatedNvLink::new("00000000:40:00.0")];
+        devices[0].nvlinks[1].active = false;
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(devices))).unwrap();
+
+        let device = Device::new(&nvml, 0).unwrap();
+        let links = device.nvlinks().unwrap();
+        assert_eq!(links.len(), 2);
+        assert!(links[0].active);
+        assert_eq!(links[0].version, Some(2));
+        assert!(links[0].has_capability(NvLinkCapability::P2pAtomics));
+        assert!(!links[0].has_capability(NvLinkCapability::SliBridge));
+        let remote = links[0].remote.as_ref().unwrap();
+        assert_eq!(remote.bus_id, "00000000:02:00.0");
+        assert_eq!(remote.device_index, Some(1));
+        assert_eq!(
+            links[0].error_counter(NvLinkErrorCounter::DlReplay),
+            Some(7)
+        );
+        assert!(!links[1].active);
+        assert_eq!(links[1].remote.as_ref().unwrap().device_index, None);
+
+        device.admin().reset_nvlink_error_counters(0).unwrap();
+        let links = device.nvlinks().unwrap();
+        assert_eq!(
+            links[0].error_counter(NvLinkErrorCounter::DlReplay),
+            Some(0)
+        );
+        assert!(Device::new(&nvml, 1).unwrap().nvlinks().unwrap().is_empty());
+    }
+
+    #[test]
+    fn nvlink_throughput_is_computed_from_counter_deltas() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            nvlinks: vec![
+                SimulatedNvLink::new("00000000:02:00.0"),
+                SimulatedNvLink::new("00000000:03:00.0"),
+            ],
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        let control = NvLinkUtilizationControl {
+            units: NvLinkCounterUnit::Packets,
+            packet_filter: NvLinkPacketTypes::READ | NvLinkPacketTypes::WRITE,
+        };
+        device
+            .admin()
+            .set_nvlink_utilization_control(1, NvLinkCounter::Counter0, control, true)
+            .unwrap();
+        assert_eq!(
+            device
+                .handler
+                .get_nvlink_utilization_control(1, NvLinkCounter::Counter0)
+                .unwrap(),
+            control
+        );
+
+        let before = device
+            .nvlink_counter_snapshot(NvLinkCounter::Counter0)
+            .unwrap();
+        backend.update(0, |d| {
+            d.nvlinks[0].utilization_counters[0].add(4_000, 2_000);
+            d.nvlinks[1].utilization_counters[0].add(10, 0);
+        });
+        device
+            .admin()
+            .freeze_nvlink_utilization_counter(1, NvLinkCounter::Counter0, true)
+            .unwrap();
+        backend.update(0, |d| d.nvlinks[1].utilization_counters[0].add(10, 0));
+        let after = NvLinkCounterSnapshot {
+            taken_at: before.taken_at + Duration::from_secs(2),
+            ..device
+                .nvlink_counter_snapshot(NvLinkCounter::Counter0)
+                .unwrap()
+        };
+        let rates = before.rates(&after);
+        assert_eq!(rates.len(), 2);
+        assert_eq!(rates[0].units, NvLinkCounterUnit::Bytes);
+        assert_eq!(rates[0].rx_per_second, 2_000.0);
+        assert_eq!(rates[0].tx_per_second, 1_000.0);
+        assert_eq!(rates[1].units, NvLinkCounterUnit::Packets);
+        assert_eq!(rates[1].rx_per_second, 5.0);
+
+        device
+            .admin()
+            .reset_nvlink_utilization_counter(0, NvLinkCounter::Counter0)
+            .unwrap();
+        assert_eq!(
+            device
+                .handler
+                .get_nvlink_utilization_counter(0, NvLinkCounter::Counter0)
+                .unwrap(),
+            (0, 0)
+        );
+        let idle = device
+            .nvlink_throughput(NvLinkCounter::Counter0, Duration::from_millis(1))
+            .unwrap();
+        assert!(idle.iter().all(|rate| rate.rx_per_second == 0.0));
+    }
+
+    #[test]
+    fn counter_snapshots_cover_active_links_and_report_errors() {
+        let mut links = vec![SimulatedNvLink::new("00000000:02:00.0"); 3];
+        links[1].active = false;
+        links[1].utilization_counters[1].supported = false;
+        links[2].utilization_counters[1].supported = false;
+        let backend = Arc::new(SimulatedBackend::new(vec![
+            SimulatedDevice {
+                nvlinks: links,
+                ..SimulatedDevice::new(0)
+            },
+            SimulatedDevice::new(1),
+        ]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+
+        let snapshot = device
+            .nvlink_counter_snapshot(NvLinkCounter::Counter0)
+            .unwrap();
+        let links: Vec<u32> = snapshot.readings.iter().map(|r| r.link).collect();
+        assert_eq!(links, vec![0, 2]);
+
+        let error = device
+            .nvlink_throughput(NvLinkCounter::Counter1, Duration::from_millis(1))
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::NotSupported);
+        assert_eq!(
+            error.function(),
+            Some("nvmlDeviceGetNvLinkUtilizationControl")
+        );
+
+        // A device without NvLink has no links to read.
+        let device = Device::new(&nvml, 1).unwrap();
+        assert!(device
+            .nvlink_counter_snapshot(NvLinkCounter::Counter1)
+            .unwrap()
+            .readings
+            .is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::os::raw::{c_uint, c_ulonglong};
+use std::time::{Duration, Instant};
+
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::{Device, Handler};
+
+/// A performance state; P0 is maximum performance and P15 minimum.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PerformanceState {
+    P(u8),
+    Unknown,
+}
+
+impl From<nvmlPstates_t> for PerformanceState {
+    fn from(state: nvmlPstates_t) -> PerformanceState {
+        match state {
+            nvmlPStates_enum_NVML_PSTATE_0..=nvmlPStates_enum_NVML_PSTATE_15 => {
+                PerformanceState::P(state as u8)
+            }
+            _ => PerformanceState::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for PerformanceState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PerformanceState::P(n) => write!(f, "P{}", n),
+            PerformanceState::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// The range the power management limit can be set to, in milliwatts.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PowerLimitConstraints {
+    pub min: u32,
+    pub max: u32,
+}
+
+/// A reading of the total energy counter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EnergyReading {
+    /// Millijoules consumed since the driver was last reloaded.
+    pub energy: u64,
+    pub taken_at: Instant,
+}
+
+/// The energy consumed between two [`EnergyReading`]s.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EnergyDelta {
+    pub joules: f64,
+    pub elapsed: Duration,
+    /// `joules` over `elapsed`; 0 if no time passed.
+    pub average_watts: f64,
+}
+
+impl EnergyReading {
+    /// Returns the energy consumed from this reading up to `later`. A counter
+    /// that went backwards is taken to have wrapped around.
+    pub fn delta(&self, later: &EnergyReading) -> EnergyDelta {
+        let millijoules = later.energy.wrapping_sub(self.energy);
+        let joules = millijoules as f64 / 1000.0;
+        let elapsed = later.taken_at.saturating_duration_since(self.taken_at);
+        let seconds = elapsed.as_secs_f64();
+        EnergyDelta {
+            joules,
+            elapsed,
+            average_watts: if seconds > 0.0 { joules / seconds } else { 0.0 },
+        }
+    }
+}
+
+impl Handler<'_> {
+    /// Current power draw of the board in milliwatts.
+    pub fn get_power_usage(&self) -> Result<u64> {
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetPowerUsage,
+            "nvmlDeviceGetPowerUsage",
+        )
+    }
+
+    /// Energy consumed since the driver was last reloaded, in millijoules.
+    pub fn get_total_energy_consumption(&self) -> Result<u64> {
+        unsafe {
+            let mut energy: c_ulonglong = 0;
+            let result = self
+                .backend
+                .nvmlDeviceGetTotalEnergyConsumption(self.dev, &mut energy as *mut c_ulonglong);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetTotalEnergyConsumption"));
+            }
+            Ok(energy)
+        }
+    }
+
+    /// The power limit in effect, in milliwatts. It can be lower than the power
+    /// management limit when another limit, such as the board's, is stricter.
+    pub fn get_enforced_power_limit(&self) -> Result<u64> {
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetEnforcedPowerLimit,
+            "nvmlDeviceGetEnforcedPowerLimit",
+        )
+    }
+
+    pub fn get_power_management_default_limit(&self) -> Result<u64> {
+        self.get_one_interger(
+            <dyn Backend>::nvmlDeviceGetPowerManagementDefaultLimit,
+            "nvmlDeviceGetPowerManagementDefaultLimit",
+        )
+    }
+
+    pub fn get_power_management_limit_constraints(&self) -> Result<PowerLimitConstraints> {
+        unsafe {
+            let mut min: c_uint = 0;
+            let mut max: c_uint = 0;
+            let result = self.backend.nvmlDeviceGetPowerManagementLimitConstraints(
+                self.dev,
+                &mut min as *mut c_uint,
+                &mut max as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetPowerManagementLimitConstraints"));
+            }
+            Ok(PowerLimitConstraints { min, max })
+        }
+    }
+
+    pub fn get_power_state(&self) -> Result<PerformanceState> {
+        unsafe {
+            let mut state: nvmlPstates_t = nvmlPStates_enum_NVML_PSTATE_UNKNOWN;
+            let result = self
+                .backend
+                .nvmlDeviceGetPowerState(self.dev, &mut state as *mut nvmlPstates_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetPowerState"));
+            }
+            Ok(state.into())
+        }
+    }
+
+    /// Whether power management is enabled.
+    pub fn get_power_management_mode(&self) -> Result<bool> {
+        unsafe {
+            let mut mode: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self
+                .backend
+                .nvmlDeviceGetPowerManagementMode(self.dev, &mut mode as *mut nvmlEnableState_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetPowerManagementMode"));
+            }
+            Ok(mode == nvmlEnableState_enum_NVML_FEATURE_ENABLED)
+        }
+    }
+}
+
+impl Device<'_> {
+    /// Current power draw in milliwatts. [`Device::power`] holds the limit.
+    pub fn power_usage(&self) -> Result<u64> {
+        self.handler.get_power_usage()
+    }
+
+    /// Reads the energy counter; pass two readings to [`EnergyReading::delta`].
+    pub fn energy_reading(&self) -> Result<EnergyReading> {
+        let energy = self.handler.get_total_energy_consumption()?;
+        Ok(EnergyReading {
+            energy,
+            taken_at: Instant::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    fn reading(energy: u64, taken_at: Instant) -> EnergyReading {
+        EnergyReading { energy, taken_at }
+    }
+
+    #[test]
+    fn delta_averages_over_the_elapsed_time() {
+        let start = Instant::now();
+        let delta = reading(1_000, start).delta(&reading(7_000, start + Duration::from_secs(3)));
+        assert_eq!(delta.joules, 6.0);
+        assert_eq!(delta.elapsed, Duration::from_secs(3));
+        assert_eq!(delta.average_watts, 2.0);
+    }
+
+    #[test]
+    fn delta_without_elapsed_time_has_no_average() {
+        let now = Instant::now();
+        let delta = reading(0, now).delta(&reading(500, now));
+        assert_eq!(delta.joules, 0.5);
+        assert_eq!(delta.average_watts, 0.0);
+
+        // Readings passed in the wrong order do not panic.
+        let later = now + Duration::from_secs(1);
+        assert_eq!(
+            reading(0, later).delta(&reading(0, now)).elapsed,
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn delta_survives_a_wrapped_counter() {
+        let start = Instant::now();
+        let delta =
+            reading(u64::MAX - 999, start).delta(&reading(1_000, start + Duration::from_secs(1)));
+        assert_eq!(delta.joules, 2.0);
+        assert_eq!(delta.average_watts, 2.0);
+    }
+
+    #[test]
+    fn power_draw_is_separate_from_the_limit() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            power_usage: 61_500,
+            performance_state: 2,
+            total_energy_consumption: u64::MAX - 499,
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        assert_eq!(device.power, 300_000);
+        assert_eq!(device.power_usage().unwrap(), 61_500);
+        let handler = &device.handler;
+        assert_eq!(handler.get_enforced_power_limit().unwrap(), 300_000);
+        assert_eq!(
+            handler.get_power_management_limit_constraints().unwrap(),
+            PowerLimitConstraints {
+                min: 150_000,
+                max: 300_000
+            }
+        );
+        assert_eq!(handler.get_power_state().unwrap(), PerformanceState::P(2));
+        assert!(handler.get_power_management_mode().unwrap());
+
+        let first = device.energy_reading().unwrap();
+        backend.update(0, |d| d.total_energy_consumption = 1_500);
+        let second = EnergyReading {
+            taken_at: first.taken_at + Duration::from_secs(2),
+            ..device.energy_reading().unwrap()
+        };
+        let delta = first.delta(&second);
+        assert_eq!(delta.joules, 2.0);
+        assert_eq!(delta.average_watts, 1.0);
+    }
+}
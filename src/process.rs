@@ -0,0 +1,155 @@
+use std::os::raw::{c_char, c_uint};
+
+use nvml_binding::*;
+
+use crate::error::{Error, Result};
+use crate::{query_list, Handler, NVML};
+
+// Process names longer than this are truncated by NVML.
+const PROCESS_NAME_BUFFER_SIZE: usize = 1024;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProcessType {
+    Compute,
+    Graphics,
+}
+
+/// A process running on a device.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    /// Bytes of GPU memory used by the process, if NVML can report it. It cannot
+    /// on Windows in WDDM mode, for example.
+    pub used_gpu_memory: Option<u64>,
+    pub process_type: ProcessType,
+    /// Filled in by [`Device::processes`](crate::Device::processes).
+    pub name: Option<String>,
+}
+
+impl ProcessInfo {
+    fn new(info: &nvmlProcessInfo_t, process_type: ProcessType) -> ProcessInfo {
+        ProcessInfo {
+            pid: info.pid,
+            used_gpu_memory: match info.usedGpuMemory {
+                // NVML_VALUE_NOT_AVAILABLE as an unsigned long long.
+                u64::MAX => None,
+                used => Some(used),
+            },
+            process_type,
+            name: None,
+        }
+    }
+}
+
+type RunningProcessesFn = unsafe fn(
+    &(dyn Backend + 'static),
+    nvmlDevice_t,
+    *mut c_uint,
+    *mut nvmlProcessInfo_t,
+) -> nvmlReturn_t;
+
+impl Handler<'_> {
+    pub fn get_compute_running_processes(&self) -> Result<Vec<ProcessInfo>> {
+        self.get_running_processes(
+            <dyn Backend>::nvmlDeviceGetComputeRunningProcesses,
+            "nvmlDeviceGetComputeRunningProcesses",
+            ProcessType::Compute,
+        )
+    }
+
+    pub fn get_graphics_running_processes(&self) -> Result<Vec<ProcessInfo>> {
+        self.get_running_processes(
+            <dyn Backend>::nvmlDeviceGetGraphicsRunningProcesses,
+            "nvmlDeviceGetGraphicsRunningProcesses",
+            ProcessType::Graphics,
+        )
+    }
+
+    fn get_running_processes(
+        &self,
+        f: RunningProcessesFn,
+        function: &'static str,
+        process_type: ProcessType,
+    ) -> Result<Vec<ProcessInfo>> {
+        unsafe {
+            let infos = query_list(|count, infos| f(&*self.backend, self.dev, count, infos))
+                .map_err(|result| self.error(result, function))?;
+            Ok(infos
+                .iter()
+                .map(|info| ProcessInfo::new(info, process_type))
+                .collect())
+        }
+    }
+}
+
+impl NVML {
+    pub fn process_name(&self, pid: u32) -> Result<String> {
+        system_process_name(&*self.backend, pid)
+    }
+}
+
+fn system_process_name(backend: &dyn Backend, pid: u32) -> Result<String> {
+    unsafe {
+        let mut name: [c_char; PROCESS_NAME_BUFFER_SIZE] = [0; PROCESS_NAME_BUFFER_SIZE];
+        let result =
+            backend.nvmlSystemGetProcessName(pid, &mut name[0], PROCESS_NAME_BUFFER_SIZE as c_uint);
+        if result != nvmlReturn_enum_NVML_SUCCESS {
+            return Err(Error::nvml(result, "nvmlSystemGetProcessName"));
+        }
+        Ok(std::ffi::CStr::from_ptr(name.as_ptr())
+            .to_string_lossy()
+            .into_owned())
+    }
+}
+
+// Asks NVML first and falls back to procfs, which also works for processes NVML
+// has no permission to inspect.
+pub(crate) fn process_name(backend: &dyn Backend, pid: u32) -> Option<String> {
+    match system_process_name(backend, pid) {
+        Ok(name) if !name.is_empty() => Some(name),
+        _ => std::fs::read_to_string(format!("/proc/{}/comm", pid))
+            .ok()
+            .map(|comm| comm.trim_end().to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice, SimulatedProcess};
+    use crate::Device;
+    use std::sync::Arc;
+
+    #[test]
+    fn processes_are_listed_with_names() {
+        let compute_processes = (0..40)
+            .map(|i| SimulatedProcess {
+                pid: 1000 + i,
+                name: format!("worker-{}", i),
+                used_gpu_memory: 1 << 20,
+            })
+            .collect();
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            compute_processes,
+            graphics_processes: vec![SimulatedProcess {
+                pid: std::process::id(),
+                name: String::new(),
+                used_gpu_memory: u64::MAX,
+            }],
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let processes = Device::new(&nvml, 0).unwrap().processes().unwrap();
+        assert_eq!(processes.len(), 41);
+        assert_eq!(processes[39].pid, 1039);
+        assert_eq!(processes[39].name.as_deref(), Some("worker-39"));
+        assert_eq!(processes[39].used_gpu_memory, Some(1 << 20));
+        assert_eq!(processes[39].process_type, ProcessType::Compute);
+
+        // Unknown to NVML, resolved through /proc/<pid>/comm.
+        let comm = std::fs::read_to_string("/proc/self/comm").ok();
+        assert_eq!(processes[40].name, comm.map(|c| c.trim_end().to_owned()));
+        assert_eq!(processes[40].used_gpu_memory, None);
+        assert_eq!(processes[40].process_type, ProcessType::Graphics);
+    }
+}
@@ -0,0 +1,212 @@
+use std::os::raw::{c_uint, c_ulonglong};
+
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::{Device, Handler};
+
+/// Why the driver retired a page of device memory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PageRetirementCause {
+    MultipleSingleBitEccErrors,
+    DoubleBitEccError,
+}
+
+impl PageRetirementCause {
+    pub const ALL: [PageRetirementCause; 2] = [
+        PageRetirementCause::MultipleSingleBitEccErrors,
+        PageRetirementCause::DoubleBitEccError,
+    ];
+}
+
+impl From<PageRetirementCause> for nvmlPageRetirementCause_t {
+    fn from(cause: PageRetirementCause) -> nvmlPageRetirementCause_t {
+        match cause {
+            PageRetirementCause::MultipleSingleBitEccErrors => {
+                nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_MULTIPLE_SINGLE_BIT_ECC_ERRORS
+            }
+            PageRetirementCause::DoubleBitEccError => {
+                nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_DOUBLE_BIT_ECC_ERROR
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetiredPage {
+    /// The hardware address of the page, as reported with Xid 63. It does not
+    /// match CUDA virtual addresses.
+    pub address: u64,
+    /// When the page was retired, as reported by the driver.
+    pub timestamp: u64,
+}
+
+/// The retired pages of a device, including pages pending retirement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetiredPages {
+    pub multiple_single_bit_ecc_errors: Vec<RetiredPage>,
+    pub double_bit_ecc_error: Vec<RetiredPage>,
+    /// Pages are pending retirement, which only takes effect after a reboot.
+    pub reboot_required: bool,
+}
+
+impl RetiredPages {
+    pub fn pages(&self, cause: PageRetirementCause) -> &[RetiredPage] {
+        match cause {
+            PageRetirementCause::MultipleSingleBitEccErrors => &self.multiple_single_bit_ecc_errors,
+            PageRetirementCause::DoubleBitEccError => &self.double_bit_ecc_error,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.multiple_single_bit_ecc_errors.is_empty() && self.double_bit_ecc_error.is_empty()
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_retired_pages(&self, cause: PageRetirementCause) -> Result<Vec<RetiredPage>> {
+        unsafe {
+            // Addresses and timestamps come in parallel buffers, so this runs the
+            // sizing protocol of `query_list` over both.
+            let mut addresses: Vec<c_ulonglong> = vec![];
+            let mut timestamps: Vec<c_ulonglong> = vec![];
+            let mut count: c_uint;
+            loop {
+                let (addresses_ptr, timestamps_ptr) = if addresses.is_empty() {
+                    (std::ptr::null_mut(), std::ptr::null_mut())
+                } else {
+                    (addresses.as_mut_ptr(), timestamps.as_mut_ptr())
+                };
+                count = addresses.len() as c_uint;
+                let result = self.backend.nvmlDeviceGetRetiredPages_v2(
+                    self.dev,
+                    cause.into(),
+                    &mut count as *mut c_uint,
+                    addresses_ptr,
+                    timestamps_ptr,
+                );
+                match result {
+                    // Some drivers answer the sizing call with success.
+                    nvmlReturn_enum_NVML_SUCCESS if addresses_ptr.is_null() && count > 0 => {}
+                    nvmlReturn_enum_NVML_SUCCESS => break,
+                    nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE
+                        if count as usize > addresses.len() => {}
+                    _ => return Err(self.error(result, "nvmlDeviceGetRetiredPages_v2")),
+                }
+                addresses.resize(count as usize, 0);
+                timestamps.resize(count as usize, 0);
+            }
+            addresses.truncate(count as usize);
+            Ok(addresses
+                .into_iter()
+                .zip(timestamps)
+                .map(|(address, timestamp)| RetiredPage { address, timestamp })
+                .collect())
+        }
+    }
+
+    /// Whether pages are pending retirement, which requires a reboot.
+    pub fn get_retired_pages_pending_status(&self) -> Result<bool> {
+        unsafe {
+            let mut pending: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self
+                .backend
+                .nvmlDeviceGetRetiredPagesPendingStatus(self.dev, &mut pending as *mut _);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetRetiredPagesPendingStatus"));
+            }
+            Ok(pending == nvmlEnableState_enum_NVML_FEATURE_ENABLED)
+        }
+    }
+}
+
+impl Device<'_> {
+    pub fn retired_pages(&self) -> Result<RetiredPages> {
+        Ok(RetiredPages {
+            multiple_single_bit_ecc_errors: self
+                .handler
+                .get_retired_pages(PageRetirementCause::MultipleSingleBitEccErrors)?,
+            double_bit_ecc_error: self
+                .handler
+                .get_retired_pages(PageRetirementCause::DoubleBitEccError)?,
+            reboot_required: self.handler.get_retired_pages_pending_status()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn sizing_answers_are_followed() {
+        let single_bit =
+            nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_MULTIPLE_SINGLE_BIT_ECC_ERRORS;
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            retired_pages: vec![(single_bit, 0x1000, 10), (single_bit, 0x2000, 11)],
+            retired_pages_sizing_succeeds: true,
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let handler = Handler::new(&nvml, 0).unwrap();
+        let pages = handler
+            .get_retired_pages(PageRetirementCause::MultipleSingleBitEccErrors)
+            .unwrap();
+        assert_eq!(
+            pages,
+            vec![
+                RetiredPage {
+                    address: 0x1000,
+                    timestamp: 10
+                },
+                RetiredPage {
+                    address: 0x2000,
+                    timestamp: 11
+                },
+            ]
+        );
+        assert!(handler
+            .get_retired_pages(PageRetirementCause::DoubleBitEccError)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn retired_pages_are_listed_by_cause() {
+        let single_bit =
+            nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_MULTIPLE_SINGLE_BIT_ECC_ERRORS;
+        let double_bit =
+            nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_DOUBLE_BIT_ECC_ERROR;
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            retired_pages: vec![
+                (single_bit, 0x1000, 1_560_000_000),
+                (double_bit, 0x2000, 1_560_000_100),
+                (single_bit, 0x3000, 1_560_000_200),
+            ],
+            retired_pages_pending: true,
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        let pages = device.retired_pages().unwrap();
+        assert!(pages.reboot_required);
+        assert_eq!(
+            pages.pages(PageRetirementCause::MultipleSingleBitEccErrors),
+            &[
+                RetiredPage {
+                    address: 0x1000,
+                    timestamp: 1_560_000_000
+                },
+                RetiredPage {
+                    address: 0x3000,
+                    timestamp: 1_560_000_200
+                },
+            ]
+        );
+        assert_eq!(pages.double_bit_ecc_error.len(), 1);
+        assert_eq!(pages.double_bit_ecc_error[0].address, 0x2000);
+    }
+}
@@ -0,0 +1,292 @@
+//! Access to the sample buffers NVML keeps per device.
+//!
+//! The driver records power, utilization and clock readings at a higher rate than
+//! is practical to poll, so short spikes show up here that instantaneous queries
+//! miss. A [`Sampler`] remembers the newest timestamp it has returned for each
+//! [`SamplingType`], so successive polls only yield new samples.
+
+use std::collections::HashMap;
+use std::os::raw::{c_uint, c_ulonglong};
+
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::{Device, Handler};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SamplingType {
+    /// Total power drawn by the GPU, in milliwatts.
+    TotalPower,
+    /// Percent of time a kernel was executing on the GPU.
+    GpuUtilization,
+    /// Percent of time device memory was being read or written.
+    MemoryUtilization,
+    /// Percent of time the video encoder was busy.
+    EncoderUtilization,
+    /// Percent of time the video decoder was busy.
+    DecoderUtilization,
+    /// Processor clock in MHz.
+    ProcessorClock,
+    /// Memory clock in MHz.
+    MemoryClock,
+}
+
+impl SamplingType {
+    pub fn as_raw(self) -> nvmlSamplingType_t {
+        match self {
+            SamplingType::TotalPower => nvmlSamplingType_enum_NVML_TOTAL_POWER_SAMPLES,
+            SamplingType::GpuUtilization => nvmlSamplingType_enum_NVML_GPU_UTILIZATION_SAMPLES,
+            SamplingType::MemoryUtilization => {
+                nvmlSamplingType_enum_NVML_MEMORY_UTILIZATION_SAMPLES
+            }
+            SamplingType::EncoderUtilization => nvmlSamplingType_enum_NVML_ENC_UTILIZATION_SAMPLES,
+            SamplingType::DecoderUtilization => nvmlSamplingType_enum_NVML_DEC_UTILIZATION_SAMPLES,
+            SamplingType::ProcessorClock => nvmlSamplingType_enum_NVML_PROCESSOR_CLK_SAMPLES,
+            SamplingType::MemoryClock => nvmlSamplingType_enum_NVML_MEMORY_CLK_SAMPLES,
+        }
+    }
+}
+
+/// An `nvmlValue_t` decoded according to the `nvmlValueType_t` NVML reported.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SampleValue {
+    Double(f64),
+    UnsignedInt(u32),
+    UnsignedLong(u64),
+    UnsignedLongLong(u64),
+    SignedLongLong(i64),
+}
+
+impl SampleValue {
+    /// Decodes `value`; `None` if NVML reported a value type this crate does not
+    /// know.
+    ///
+    /// # Safety
+    ///
+    /// `value` must have been filled in by NVML as `value_type`.
+    pub unsafe fn from_raw(
+        value_type: nvmlValueType_t,
+        value: &nvmlValue_t,
+    ) -> Option<SampleValue> {
+        match value_type {
+            nvmlValueType_enum_NVML_VALUE_TYPE_DOUBLE => Some(SampleValue::Double(value.dVal)),
+            nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_INT => {
+                Some(SampleValue::UnsignedInt(value.uiVal))
+            }
+            // `unsigned long` is 32 bits wide on Windows.
+            #[allow(clippy::unnecessary_cast)]
+            nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_LONG => {
+                Some(SampleValue::UnsignedLong(value.ulVal as u64))
+            }
+            nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_LONG_LONG => {
+                Some(SampleValue::UnsignedLongLong(value.ullVal))
+            }
+            nvmlValueType_enum_NVML_VALUE_TYPE_SIGNED_LONG_LONG => {
+                Some(SampleValue::SignedLongLong(value.sllVal))
+            }
+            _ => None,
+        }
+    }
+
+    /// The value widened to `f64`, which is lossy only beyond 2^53.
+    pub fn as_f64(self) -> f64 {
+        match self {
+            SampleValue::Double(v) => v,
+            SampleValue::UnsignedInt(v) => v as f64,
+            SampleValue::UnsignedLong(v) | SampleValue::UnsignedLongLong(v) => v as f64,
+            SampleValue::SignedLongLong(v) => v as f64,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sample {
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub value: SampleValue,
+}
+
+// Sizes a buffer for a sample query: a call without a buffer reports the number
+// of samples, and an empty buffer reports `NOT_FOUND`. Unlike list queries, NVML
+// fills as many samples as fit and may not report INSUFFICIENT_SIZE, so the first
+// call only sizes the buffer.
+pub(crate) unsafe fn query_samples<T, F>(mut query: F) -> std::result::Result<Vec<T>, nvmlReturn_t>
+where
+    T: Copy,
+    F: FnMut(*mut c_uint, *mut T) -> nvmlReturn_t,
+{
+    let mut count: c_uint = 0;
+    match query(&mut count as *mut c_uint, std::ptr::null_mut()) {
+        nvmlReturn_enum_NVML_SUCCESS | nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE
+            if count > 0 => {}
+        nvmlReturn_enum_NVML_SUCCESS | nvmlReturn_enum_NVML_ERROR_NOT_FOUND => return Ok(vec![]),
+        result => return Err(result),
+    }
+    let mut buffer: Vec<T> = vec![std::mem::zeroed(); count as usize];
+    match query(&mut count as *mut c_uint, buffer.as_mut_ptr()) {
+        nvmlReturn_enum_NVML_SUCCESS => {}
+        nvmlReturn_enum_NVML_ERROR_NOT_FOUND => return Ok(vec![]),
+        result => return Err(result),
+    }
+    buffer.truncate(count as usize);
+    Ok(buffer)
+}
+
+impl Handler<'_> {
+    /// Returns the buffered samples of `sampling_type` newer than
+    /// `last_seen_timestamp`, oldest first. Pass 0 to get the whole buffer.
+    ///
+    /// An empty buffer is not an error; NVML's `NOT_FOUND` yields no samples.
+    pub fn get_samples(
+        &self,
+        sampling_type: SamplingType,
+        last_seen_timestamp: u64,
+    ) -> Result<Vec<Sample>> {
+        let mut value_type: nvmlValueType_t = 0;
+        let samples: Vec<nvmlSample_t> = unsafe {
+            query_samples(|count, samples| {
+                self.backend.nvmlDeviceGetSamples(
+                    self.dev,
+                    sampling_type.as_raw(),
+                    last_seen_timestamp as c_ulonglong,
+                    &mut value_type as *mut nvmlValueType_t,
+                    count,
+                    samples,
+                )
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetSamples"))?;
+        let mut decoded = Vec::with_capacity(samples.len());
+        for sample in samples.iter() {
+            decoded.push(Sample {
+                timestamp: sample.timeStamp,
+                value: self.decode_sample_value(
+                    value_type,
+                    &sample.sampleValue,
+                    "nvmlDeviceGetSamples",
+                )?,
+            });
+        }
+        decoded.sort_by_key(|sample| sample.timestamp);
+        Ok(decoded)
+    }
+
+    // Decodes a value `function` reported as `value_type`; a type this crate does
+    // not know is an error.
+    pub(crate) fn decode_sample_value(
+        &self,
+        value_type: nvmlValueType_t,
+        value: &nvmlValue_t,
+        function: &'static str,
+    ) -> Result<SampleValue> {
+        unsafe { SampleValue::from_raw(value_type, value) }
+            .ok_or_else(|| self.error(nvmlReturn_enum_NVML_ERROR_UNKNOWN, function))
+    }
+}
+
+/// Polls the sample buffers of one device, returning each sample once.
+pub struct Sampler<'nvml> {
+    handler: Handler<'nvml>,
+    last_seen: HashMap<SamplingType, u64>,
+}
+
+impl<'nvml> Sampler<'nvml> {
+    pub fn new(handler: Handler<'nvml>) -> Self {
+        Sampler {
+            handler,
+            last_seen: HashMap::new(),
+        }
+    }
+
+    /// Returns the samples of `sampling_type` recorded since the previous poll of
+    /// that type, or the whole buffer on the first poll.
+    pub fn poll(&mut self, sampling_type: SamplingType) -> Result<Vec<Sample>> {
+        let last_seen = self.last_seen.get(&sampling_type).copied().unwrap_or(0);
+        let samples = self.handler.get_samples(sampling_type, last_seen)?;
+        if let Some(newest) = samples.last() {
+            self.last_seen.insert(sampling_type, newest.timestamp);
+        }
+        Ok(samples)
+    }
+
+    /// The newest timestamp returned for `sampling_type`, in microseconds.
+    pub fn last_seen(&self, sampling_type: SamplingType) -> Option<u64> {
+        self.last_seen.get(&sampling_type).copied()
+    }
+
+    /// Forgets the cursors so the next poll returns whole buffers again.
+    pub fn reset(&mut self) {
+        self.last_seen.clear();
+    }
+}
+
+impl<'nvml> Device<'nvml> {
+    pub fn sampler(&self) -> Sampler<'nvml> {
+        Sampler::new(self.handler.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice, SimulatedSample};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn query_samples_sizes_the_buffer_first() {
+        let samples = [3u32, 1, 2];
+        let mut calls = 0;
+        let list = unsafe {
+            query_samples(|count, buffer: *mut u32| {
+                calls += 1;
+                if buffer.is_null() {
+                    *count = samples.len() as c_uint;
+                    return nvmlReturn_enum_NVML_SUCCESS;
+                }
+                std::ptr::copy_nonoverlapping(samples.as_ptr(), buffer, 2);
+                *count = 2;
+                nvmlReturn_enum_NVML_SUCCESS
+            })
+        };
+        assert_eq!(list.unwrap(), vec![3, 1]);
+        assert_eq!(calls, 2);
+
+        let empty = unsafe { query_samples(|_, _: *mut u32| nvmlReturn_enum_NVML_ERROR_NOT_FOUND) };
+        assert_eq!(empty, Ok(vec![]));
+        let error =
+            unsafe { query_samples(|_, _: *mut u32| nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED) };
+        assert_eq!(error, Err(nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED));
+    }
+
+    #[test]
+    fn sampler_returns_only_new_samples() {
+        let power = |timestamp, value| SimulatedSample {
+            sampling_type: nvmlSamplingType_enum_NVML_TOTAL_POWER_SAMPLES,
+            timestamp,
+            value,
+        };
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            samples: vec![power(100, 45_000), power(200, 250_000)],
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let mut sampler = Device::new(&nvml, 0).unwrap().sampler();
+
+        let samples = sampler.poll(SamplingType::TotalPower).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].timestamp, 200);
+        assert_eq!(samples[1].value, SampleValue::UnsignedInt(250_000));
+        assert!(sampler.poll(SamplingType::TotalPower).unwrap().is_empty());
+        assert!(sampler
+            .poll(SamplingType::GpuUtilization)
+            .unwrap()
+            .is_empty());
+
+        backend.update(0, |d| d.samples.push(power(300, 60_000)));
+        let samples = sampler.poll(SamplingType::TotalPower).unwrap();
+        assert_eq!(samples.len(), 1);
+        assert_eq!(samples[0].value.as_f64(), 60_000.0);
+        assert_eq!(sampler.last_seen(SamplingType::TotalPower), Some(300));
+    }
+}
@@ -0,0 +1,319 @@
+//! Finding a device by something more stable than its index.
+//!
+//! Indices follow the enumeration order of the driver and can change across
+//! reboots; UUIDs, serials and PCI bus ids do not. [`DeviceSelector`] parses the
+//! same identifiers as `nvidia-smi -i`.
+
+use std::ffi::CString;
+use std::fmt;
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_uint};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use nvml_binding::*;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::{Device, Handler, NVML};
+
+// Length of a full UUID such as `GPU-3f2a6b4c-...`.
+const UUID_LEN: usize = 40;
+
+// Serials are 13 digits, often with a leading zero; indices are short.
+const MAX_INDEX_DIGITS: usize = 9;
+
+/// One way of naming a device.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceSelector {
+    Index(u32),
+    /// A full UUID or a prefix of one, starting with `GPU-`.
+    Uuid(String),
+    /// `domain:bus:device.function`, with a 4 or 8 digit domain.
+    PciBusId(String),
+    Serial(String),
+}
+
+impl DeviceSelector {
+    /// The device the selector names. A UUID prefix must match exactly one
+    /// device.
+    pub fn resolve<'nvml>(&self, nvml: &'nvml NVML) -> Result<Handler<'nvml>> {
+        match self {
+            DeviceSelector::Index(index) => Handler::new(nvml, *index),
+            // NVML compares UUIDs case-sensitively against its own lower case hex.
+            DeviceSelector::Uuid(uuid) if uuid.len() == UUID_LEN => {
+                let canonical = format!("GPU-{}", uuid[4..].to_lowercase());
+                match Handler::by_uuid(nvml, &canonical) {
+                    Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                        resolve_uuid_prefix(nvml, uuid)
+                    }
+                    result => result,
+                }
+            }
+            DeviceSelector::Uuid(prefix) => resolve_uuid_prefix(nvml, prefix),
+            DeviceSelector::PciBusId(bus_id) => Handler::by_pci_bus_id(nvml, bus_id),
+            DeviceSelector::Serial(serial) => Handler::by_serial(nvml, serial),
+        }
+    }
+}
+
+/// Parses `0`, `GPU-3f2a` (a unique prefix), `00000000:3B:00.0` or a serial such
+/// as `0323118000001`.
+impl FromStr for DeviceSelector {
+    type Err = Error;
+
+    fn from_str(selector: &str) -> Result<DeviceSelector> {
+        let selector = selector.trim();
+        if selector.is_empty() {
+            return Err(Error::invalid_input("empty device selector"));
+        }
+        if selector.len() >= 4 && selector[..4].eq_ignore_ascii_case("GPU-") {
+            return Ok(DeviceSelector::Uuid(selector.to_owned()));
+        }
+        if selector.contains(':') {
+            return Ok(DeviceSelector::PciBusId(selector.to_owned()));
+        }
+        let is_index = selector.len() <= MAX_INDEX_DIGITS
+            && selector.bytes().all(|b| b.is_ascii_digit())
+            && (selector == "0" || !selector.starts_with('0'));
+        match selector.parse() {
+            Ok(index) if is_index => Ok(DeviceSelector::Index(index)),
+            _ => Ok(DeviceSelector::Serial(selector.to_owned())),
+        }
+    }
+}
+
+impl fmt::Display for DeviceSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeviceSelector::Index(index) => write!(f, "{}", index),
+            DeviceSelector::Uuid(id)
+            | DeviceSelector::PciBusId(id)
+            | DeviceSelector::Serial(id) => f.write_str(id),
+        }
+    }
+}
+
+impl<'nvml> Handler<'nvml> {
+    pub fn by_uuid(nvml: &'nvml NVML, uuid: &str) -> Result<Self> {
+        Self::by_identifier(
+            &nvml.backend,
+            uuid,
+            <dyn Backend>::nvmlDeviceGetHandleByUUID,
+            "nvmlDeviceGetHandleByUUID",
+        )
+        .map_err(|e| e.with_device_uuid(uuid))
+    }
+
+    pub fn by_serial(nvml: &'nvml NVML, serial: &str) -> Result<Self> {
+        Self::by_identifier(
+            &nvml.backend,
+            serial,
+            <dyn Backend>::nvmlDeviceGetHandleBySerial,
+            "nvmlDeviceGetHandleBySerial",
+        )
+    }
+
+    /// Accepts the `domain:bus:device.function` format with a 4 or 8 digit
+    /// domain, as NVML and sysfs print it.
+    pub fn by_pci_bus_id(nvml: &'nvml NVML, bus_id: &str) -> Result<Self> {
+        Self::by_identifier(
+            &nvml.backend,
+            bus_id,
+            <dyn Backend>::nvmlDeviceGetHandleByPciBusId_v2,
+            "nvmlDeviceGetHandleByPciBusId_v2",
+        )
+    }
+
+    /// The device named by a selector string; see [`DeviceSelector`].
+    pub fn select(nvml: &'nvml NVML, selector: &str) -> Result<Self> {
+        selector.parse::<DeviceSelector>()?.resolve(nvml)
+    }
+
+    fn by_identifier(
+        backend: &Arc<dyn Backend>,
+        identifier: &str,
+        f: HandleByIdentifierFn,
+        function: &'static str,
+    ) -> Result<Self> {
+        let not_found =
+            |error: Error| error.with_message(&format!("no device matches {:?}", identifier));
+        // No device has an identifier with an interior NUL.
+        let raw = CString::new(identifier)
+            .map_err(|_| not_found(Error::from(nvmlReturn_enum_NVML_ERROR_NOT_FOUND)))?;
+        unsafe {
+            let mut dev: nvmlDevice_t = std::ptr::null_mut();
+            let result = f(&**backend, raw.as_ptr(), &mut dev as *mut nvmlDevice_t);
+            match result {
+                nvmlReturn_enum_NVML_SUCCESS => {}
+                nvmlReturn_enum_NVML_ERROR_NOT_FOUND => {
+                    return Err(not_found(Error::nvml(result, function)))
+                }
+                _ => return Err(Error::nvml(result, function)),
+            }
+            let mut index: c_uint = 0;
+            let result = backend.nvmlDeviceGetIndex(dev, &mut index as *mut c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(Error::nvml(result, "nvmlDeviceGetIndex"));
+            }
+            Ok(Handler {
+                dev,
+                index,
+                backend: backend.clone(),
+                nvml: PhantomData,
+            })
+        }
+    }
+}
+
+impl<'nvml> Device<'nvml> {
+    pub fn by_uuid(nvml: &'nvml NVML, uuid: &str) -> Result<Self> {
+        Device::from_handler(Handler::by_uuid(nvml, uuid)?)
+    }
+
+    pub fn by_serial(nvml: &'nvml NVML, serial: &str) -> Result<Self> {
+        Device::from_handler(Handler::by_serial(nvml, serial)?)
+    }
+
+    pub fn by_pci_bus_id(nvml: &'nvml NVML, bus_id: &str) -> Result<Self> {
+        Device::from_handler(Handler::by_pci_bus_id(nvml, bus_id)?)
+    }
+
+    /// The device named by a selector string; see [`DeviceSelector`].
+    pub fn select(nvml: &'nvml NVML, selector: &str) -> Result<Self> {
+        Device::from_handler(Handler::select(nvml, selector)?)
+    }
+}
+
+type HandleByIdentifierFn =
+    unsafe fn(&(dyn Backend + 'static), *const c_char, *mut nvmlDevice_t) -> nvmlReturn_t;
+
+// Matches case-insensitively, like nvidia-smi. Devices that cannot be queried,
+// such as a lost GPU, cannot match and do not fail the lookup.
+fn resolve_uuid_prefix<'nvml>(nvml: &'nvml NVML, prefix: &str) -> Result<Handler<'nvml>> {
+    let prefix = prefix.to_lowercase();
+    let mut matches = vec![];
+    for index in 0..nvml.device_count()? {
+        let handler = match Handler::new(nvml, index) {
+            Ok(handler) => handler,
+            Err(_) => continue,
+        };
+        match handler.get_uuid() {
+            Ok(uuid) if uuid.to_lowercase().starts_with(&prefix) => matches.push(handler),
+            _ => {}
+        }
+    }
+    match matches.len() {
+        1 => Ok(matches.remove(0)),
+        0 => Err(Error::from(nvmlReturn_enum_NVML_ERROR_NOT_FOUND)
+            .with_message(&format!("no device UUID starts with {:?}", prefix))),
+        n => Err(Error::from(nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT)
+            .with_message(&format!("{} device UUIDs start with {:?}", n, prefix))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice};
+
+    fn parse(selector: &str) -> DeviceSelector {
+        selector.parse().unwrap()
+    }
+
+    #[test]
+    fn selectors_are_told_apart_by_shape() {
+        assert_eq!(parse("0"), DeviceSelector::Index(0));
+        assert_eq!(parse(" 12\n"), DeviceSelector::Index(12));
+        assert_eq!(parse("123456789"), DeviceSelector::Index(123456789));
+        assert_eq!(
+            parse("gpu-3f2a"),
+            DeviceSelector::Uuid("gpu-3f2a".to_owned())
+        );
+        assert_eq!(
+            parse("00000000:3B:00.0"),
+            DeviceSelector::PciBusId("00000000:3B:00.0".to_owned())
+        );
+        assert_eq!(
+            parse("0000:3b:00.0"),
+            DeviceSelector::PciBusId("0000:3b:00.0".to_owned())
+        );
+    }
+
+    #[test]
+    fn long_or_zero_padded_numbers_are_serials() {
+        for serial in ["0323118000001", "01", "1234567890", "12a"] {
+            assert_eq!(parse(serial), DeviceSelector::Serial(serial.to_owned()));
+        }
+    }
+
+    #[test]
+    fn empty_selectors_are_invalid_input() {
+        for selector in ["", "  \n"] {
+            assert_eq!(
+                selector.parse::<DeviceSelector>().unwrap_err().kind(),
+                ErrorKind::InvalidInput
+            );
+        }
+    }
+
+    #[test]
+    fn selectors_display_as_parsed() {
+        for selector in ["7", "GPU-3f2a", "00000000:3B:00.0", "0323118000001"] {
+            assert_eq!(parse(selector).to_string(), selector);
+        }
+    }
+
+    #[test]
+    fn devices_are_selected_by_stable_identifiers() {
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::with_gpus(3))).unwrap();
+        let uuid = "GPU-00000002-0000-0000-0000-000000000000";
+        assert_eq!(Handler::by_uuid(&nvml, uuid).unwrap().index(), 2);
+        assert_eq!(Device::by_uuid(&nvml, uuid).unwrap().uuid, uuid);
+        assert_eq!(
+            Handler::by_serial(&nvml, "0323118000001").unwrap().index(),
+            1
+        );
+        assert_eq!(
+            Handler::by_pci_bus_id(&nvml, "0000:03:00.0")
+                .unwrap()
+                .index(),
+            2
+        );
+        let error = Handler::by_serial(&nvml, "0323118000009").err().unwrap();
+        assert_eq!(error.kind(), ErrorKind::NotFound);
+
+        for (selector, index) in [
+            ("0", 0),
+            ("gpu-00000001", 1),
+            (uuid, 2),
+            ("00000000:02:00.0", 1),
+            ("0323118000000", 0),
+        ] {
+            assert_eq!(Handler::select(&nvml, selector).unwrap().index(), index);
+        }
+        assert_eq!(
+            Handler::select(&nvml, "GPU-0000000").err().unwrap().kind(),
+            ErrorKind::InvalidArgument
+        );
+        assert_eq!(
+            Handler::select(&nvml, "GPU-1").err().unwrap().kind(),
+            ErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn uuid_selectors_ignore_case_and_lost_devices() {
+        let mut devices: Vec<SimulatedDevice> = (0..3).map(SimulatedDevice::new).collect();
+        devices[1].lost = true;
+        devices[2].uuid = "GPU-3f2a6b4c-0000-0000-0000-00000000abcd".to_owned();
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(devices))).unwrap();
+        for selector in [
+            "GPU-3F2A6B4C-0000-0000-0000-00000000ABCD",
+            "gpu-3f2a6b4c-0000-0000-0000-00000000abcd",
+            "GPU-3F2A",
+        ] {
+            assert_eq!(Handler::select(&nvml, selector).unwrap().index(), 2);
+        }
+        assert_eq!(Handler::select(&nvml, "GPU-0000").unwrap().index(), 0);
+    }
+}
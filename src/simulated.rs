@@ -0,0 +1,3207 @@
+//! An in-memory [`Backend`] that simulates NVIDIA GPUs.
+//!
+//! Tests declare the GPUs they want and hand the backend to
+//! [`NVML::with_backend`](crate::NVML::with_backend); everything above the FFI layer
+//! then runs unchanged on machines without a driver.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use nvml_rs::simulated::{SimulatedBackend, SimulatedDevice};
+//!
+//! let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+//!     name: "Tesla T4".to_owned(),
+//!     temperature: 45,
+//!     ..SimulatedDevice::new(0)
+//! }]));
+//! let nvml = nvml_rs::NVML::with_backend(backend.clone()).unwrap();
+//! let device = nvml_rs::Device::new(&nvml, 0).unwrap();
+//! assert_eq!(device.model, "Tesla T4");
+//!
+//! backend.update(0, |device| device.temperature = 90);
+//! let temperature = device.get_temperature(nvml_rs::DeviceSensorType::GPU);
+//! assert_eq!(temperature.unwrap(), 90);
+//! ```
+//!
+//! Entry points the simulation does not model report `NVML_ERROR_NOT_SUPPORTED`.
+
+use std::collections::{HashMap, VecDeque};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_uint, c_ulong, c_ulonglong};
+use std::sync::{Condvar, Mutex};
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
+
+use nvml_binding::*;
+
+/// A process running on a simulated GPU.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedProcess {
+    pub pid: u32,
+    /// Left empty, `nvmlSystemGetProcessName` reports the process as not found.
+    pub name: String,
+    /// Bytes of GPU memory used by the process.
+    pub used_gpu_memory: u64,
+}
+
+/// A process in the accounting buffer of a simulated GPU.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SimulatedAccountedProcess {
+    pub pid: u32,
+    pub gpu_utilization: u32,
+    pub memory_utilization: u32,
+    pub max_memory_usage: u64,
+    /// Milliseconds the compute context was active.
+    pub time: u64,
+    /// CPU timestamp in microseconds.
+    pub start_time: u64,
+    pub running: bool,
+}
+
+/// An entry of the per-process utilization buffer of a simulated GPU.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SimulatedProcessUtilization {
+    pub pid: u32,
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub sm: u32,
+    pub memory: u32,
+    pub encoder: u32,
+    pub decoder: u32,
+}
+
+/// A vGPU type a simulated GPU supports.
+#[derive(Debug, Clone)]
+pub struct SimulatedVgpuType {
+    /// Identifies the type across every device of the backend.
+    pub id: u32,
+    pub class: String,
+    pub name: String,
+    pub device_id: u64,
+    pub subsystem_id: u64,
+    pub framebuffer_size: u64,
+    /// The maximum resolution of each display head.
+    pub resolutions: Vec<(u32, u32)>,
+    pub license: String,
+    /// `None` while the frame rate limiter is disabled.
+    pub frame_rate_limit: Option<u32>,
+    pub max_instances: u32,
+    pub max_instances_per_vm: u32,
+}
+
+impl SimulatedVgpuType {
+    /// Returns a 4 GB Quadro vGPU type named `name`.
+    pub fn new(id: u32, name: &str) -> SimulatedVgpuType {
+        SimulatedVgpuType {
+            id,
+            class: "Quadro".to_owned(),
+            name: name.to_owned(),
+            device_id: 0x1db4_10de,
+            subsystem_id: 0x1252_10de,
+            framebuffer_size: 4 << 30,
+            resolutions: vec![(4096, 2160); 4],
+            license: "Quadro-Virtual-DWS,5.0;GRID-Virtual-WS,2.0".to_owned(),
+            frame_rate_limit: Some(60),
+            max_instances: 4,
+            max_instances_per_vm: 1,
+        }
+    }
+}
+
+/// An NVENC session on a simulated GPU or vGPU instance.
+#[derive(Debug, Copy, Clone)]
+pub struct SimulatedEncoderSession {
+    pub session_id: u32,
+    pub pid: u32,
+    /// One of the `nvmlEncoderQueryType_enum_*` constants.
+    pub codec: nvmlEncoderType_t,
+    pub resolution: (u32, u32),
+    pub average_fps: u32,
+    /// Microseconds.
+    pub average_latency: u32,
+}
+
+impl Default for SimulatedEncoderSession {
+    fn default() -> SimulatedEncoderSession {
+        SimulatedEncoderSession {
+            session_id: 0,
+            pid: 0,
+            codec: nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_H264,
+            resolution: (1920, 1080),
+            average_fps: 0,
+            average_latency: 0,
+        }
+    }
+}
+
+/// An NvFBC session on a simulated GPU or vGPU instance.
+#[derive(Debug, Copy, Clone)]
+pub struct SimulatedFbcSession {
+    pub session_id: u32,
+    pub pid: u32,
+    pub display_ordinal: u32,
+    /// One of the `nvmlFBCSessionType_enum_*` constants.
+    pub session_type: nvmlFBCSessionType_t,
+    /// `NVML_NVFBC_SESSION_FLAG_*` bitmask.
+    pub flags: u32,
+    pub max_resolution: (u32, u32),
+    pub resolution: (u32, u32),
+    pub average_fps: u32,
+    /// Microseconds.
+    pub average_latency: u32,
+}
+
+impl Default for SimulatedFbcSession {
+    fn default() -> SimulatedFbcSession {
+        SimulatedFbcSession {
+            session_id: 0,
+            pid: 0,
+            display_ordinal: 0,
+            session_type: nvmlFBCSessionType_enum_NVML_FBC_SESSION_TYPE_TOSYS,
+            flags: 0,
+            max_resolution: (4096, 2160),
+            resolution: (1920, 1080),
+            average_fps: 0,
+            average_latency: 0,
+        }
+    }
+}
+
+/// An entry of the utilization buffer of a simulated vGPU instance.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SimulatedVgpuUtilization {
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub sm: u32,
+    pub memory: u32,
+    pub encoder: u32,
+    pub decoder: u32,
+}
+
+/// A vGPU instance running on a simulated GPU.
+#[derive(Debug, Clone)]
+pub struct SimulatedVgpuInstance {
+    /// The instance handle, unique across every device of the backend.
+    pub id: u32,
+    pub vm_id: String,
+    /// One of the `nvmlVgpuVmIdType_NVML_VGPU_VM_ID_*` constants.
+    pub vm_id_type: nvmlVgpuVmIdType_t,
+    pub uuid: String,
+    pub vm_driver_version: String,
+    pub fb_usage: u64,
+    pub licensed: bool,
+    /// Id of one of the device's `vgpu_types`.
+    pub vgpu_type: u32,
+    /// `None` while the frame rate limiter is disabled.
+    pub frame_rate_limit: Option<u32>,
+    pub encoder_capacity: u32,
+    pub encoder_sessions: Vec<SimulatedEncoderSession>,
+    pub fbc_sessions: Vec<SimulatedFbcSession>,
+    pub accounting_enabled: bool,
+    pub accounted_processes: Vec<SimulatedAccountedProcess>,
+    /// Processes in the guest; they name the `process_utilization` entries.
+    pub processes: Vec<SimulatedProcess>,
+    /// Served by `nvmlDeviceGetVgpuUtilization`.
+    pub utilization: Vec<SimulatedVgpuUtilization>,
+    /// Served by `nvmlDeviceGetVgpuProcessUtilization`.
+    pub process_utilization: Vec<SimulatedProcessUtilization>,
+    /// The vGPU version the guest driver runs; only reported once
+    /// `vm_driver_version` is set.
+    pub guest_vgpu_version: u32,
+}
+
+impl SimulatedVgpuInstance {
+    /// Returns an idle, licensed instance of `vgpu_type` in the VM with domain id
+    /// `id`.
+    pub fn new(id: u32, vgpu_type: u32) -> SimulatedVgpuInstance {
+        SimulatedVgpuInstance {
+            id,
+            vm_id: id.to_string(),
+            vm_id_type: nvmlVgpuVmIdType_NVML_VGPU_VM_ID_DOMAIN_ID,
+            uuid: format!("{:08x}-0000-0000-0000-000000000000", id),
+            vm_driver_version: "440.33.01".to_owned(),
+            fb_usage: 0,
+            licensed: true,
+            vgpu_type,
+            frame_rate_limit: Some(60),
+            encoder_capacity: 100,
+            encoder_sessions: vec![],
+            fbc_sessions: vec![],
+            accounting_enabled: false,
+            accounted_processes: vec![],
+            processes: vec![],
+            utilization: vec![],
+            process_utilization: vec![],
+            guest_vgpu_version: SUPPORTED_VGPU_VERSIONS.1,
+        }
+    }
+}
+
+/// An entry of a simulated device's sample buffer.
+#[derive(Debug, Copy, Clone)]
+pub struct SimulatedSample {
+    /// One of the `nvmlSamplingType_enum_*` constants.
+    pub sampling_type: nvmlSamplingType_t,
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    /// Reported as an unsigned int, as NVML does for every sampling type.
+    pub value: u32,
+}
+
+/// An NvLink of a simulated GPU.
+#[derive(Debug, Clone)]
+pub struct SimulatedNvLink {
+    pub active: bool,
+    /// Without one, the version query rejects the link with
+    /// `NVML_ERROR_INVALID_ARGUMENT`.
+    pub version: Option<u32>,
+    /// Bitmask of the capabilities the link has, bit n for `nvmlNvLinkCapability_t`
+    /// n.
+    pub capabilities: u32,
+    /// PCI bus id of the far end.
+    pub remote_pci_bus_id: String,
+    pub remote_pci_device_id: u32,
+    /// Indexed by `nvmlNvLinkErrorCounter_t`.
+    pub error_counters: [u64; nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_COUNT as usize],
+    /// The two utilization counters.
+    pub utilization_counters: [SimulatedNvLinkCounter; 2],
+}
+
+/// An NvLink utilization counter of a simulated GPU.
+#[derive(Debug, Copy, Clone)]
+pub struct SimulatedNvLinkCounter {
+    pub units: nvmlNvLinkUtilizationCountUnits_t,
+    pub packet_filter: nvmlNvLinkUtilizationCountPktTypes_t,
+    pub rx: u64,
+    pub tx: u64,
+    pub frozen: bool,
+    /// Cleared, every query of the counter reports `NVML_ERROR_NOT_SUPPORTED`.
+    pub supported: bool,
+}
+
+impl Default for SimulatedNvLinkCounter {
+    fn default() -> SimulatedNvLinkCounter {
+        SimulatedNvLinkCounter {
+            units: nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_BYTES,
+            packet_filter:
+                nvmlNvLinkUtilizationCountPktTypes_enum_NVML_NVLINK_COUNTER_PKTFILTER_ALL,
+            rx: 0,
+            tx: 0,
+            frozen: false,
+            supported: true,
+        }
+    }
+}
+
+impl SimulatedNvLinkCounter {
+    /// Counts traffic unless the counter is frozen.
+    pub fn add(&mut self, rx: u64, tx: u64) {
+        if !self.frozen {
+            self.rx = self.rx.wrapping_add(rx);
+            self.tx = self.tx.wrapping_add(tx);
+        }
+    }
+}
+
+impl SimulatedNvLink {
+    /// Returns an active NvLink 2 to the V100 at `remote_pci_bus_id` with every
+    /// capability but SLI.
+    pub fn new(remote_pci_bus_id: &str) -> SimulatedNvLink {
+        SimulatedNvLink {
+            active: true,
+            version: Some(2),
+            capabilities: 0b10_1111,
+            remote_pci_bus_id: remote_pci_bus_id.to_owned(),
+            remote_pci_device_id: 0x1db1_10de,
+            error_counters: [0; nvmlNvLinkErrorCounter_enum_NVML_NVLINK_ERROR_COUNT as usize],
+            utilization_counters: Default::default(),
+        }
+    }
+}
+
+/// An S-class unit enclosing some of the simulated GPUs.
+#[derive(Debug, Clone, Default)]
+pub struct SimulatedUnit {
+    /// Indices of the GPUs in the unit.
+    pub devices: Vec<u32>,
+}
+
+/// The state of one simulated GPU.
+#[derive(Debug, Clone)]
+pub struct SimulatedDevice {
+    pub name: String,
+    pub uuid: String,
+    pub serial: String,
+    pub minor_number: u32,
+    pub pci_domain: u32,
+    pub pci_bus: u32,
+    pub pci_device: u32,
+    pub pci_device_id: u32,
+    pub pci_subsystem_id: u32,
+    pub pcie_link_generation: u32,
+    pub pcie_link_width: u32,
+    /// Bytes of framebuffer memory.
+    pub memory_total: u64,
+    pub memory_used: u64,
+    /// Bytes of BAR1 memory.
+    pub bar1_total: u64,
+    pub bar1_used: u64,
+    /// Current clocks in MHz.
+    pub graphics_clock: u32,
+    pub sm_clock: u32,
+    pub memory_clock: u32,
+    pub video_clock: u32,
+    /// Clocks in MHz indexed by `nvmlClockType_t`: graphics, SM, memory, video.
+    pub max_clocks: [u32; 4],
+    pub applications_clocks: [u32; 4],
+    pub default_applications_clocks: [u32; 4],
+    /// Whether reading the applications clocks is restricted to root: queries
+    /// of them then fail with `NVML_ERROR_NO_PERMISSION`.
+    pub applications_clocks_restricted: bool,
+    pub max_customer_boost_clocks: [u32; 4],
+    /// Memory clocks with the graphics clocks they pair with, in MHz.
+    pub supported_clocks: Vec<(u32, Vec<u32>)>,
+    /// GPU core temperature in degrees C.
+    pub temperature: u32,
+    /// Utilization in percent. A codec utilization of `None` makes the device
+    /// report it as not supported.
+    pub gpu_utilization: u32,
+    pub memory_utilization: u32,
+    pub encoder_utilization: Option<u32>,
+    pub decoder_utilization: Option<u32>,
+    /// Power management limit in milliwatts.
+    pub power_limit: u32,
+    pub default_power_limit: u32,
+    pub power_limit_constraints: (u32, u32),
+    /// Power draw in milliwatts.
+    pub power_usage: u32,
+    /// Energy counter in millijoules.
+    pub total_energy_consumption: u64,
+    /// Performance state, 0 to 15.
+    pub performance_state: u32,
+    pub power_management_enabled: bool,
+    pub ecc_enabled: bool,
+    pub ecc_pending: bool,
+    /// Memory locations with ECC counters, as `nvmlMemoryLocation_t`.
+    pub ecc_locations: Vec<nvmlMemoryLocation_t>,
+    /// ECC error counts indexed by `nvmlMemoryErrorType_t`,
+    /// `nvmlEccCounterType_t` and `nvmlMemoryLocation_t`.
+    pub ecc_errors: [[[u64; nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_COUNT as usize]; 2]; 2],
+    /// Retired pages as (`nvmlPageRetirementCause_t`, address, timestamp).
+    pub retired_pages: Vec<(nvmlPageRetirementCause_t, u64, u64)>,
+    pub retired_pages_pending: bool,
+    /// Whether the call sizing the retired pages succeeds, as it does on some
+    /// drivers, instead of failing with `NVML_ERROR_INSUFFICIENT_SIZE`.
+    pub retired_pages_sizing_succeeds: bool,
+    /// `nvmlClocksThrottleReason*` bitmasks.
+    pub throttle_reasons: u64,
+    pub supported_throttle_reasons: u64,
+    /// Cumulative violation time in nanoseconds, indexed by
+    /// `nvmlPerfPolicyType_t`.
+    pub violation_times: [u64; nvmlPerfPolicyType_enum_NVML_PERF_POLICY_COUNT as usize],
+    pub cuda_compute_capability: (i32, i32),
+    pub compute_processes: Vec<SimulatedProcess>,
+    pub accounting_enabled: bool,
+    /// Without accounting, queries of the buffer are not supported.
+    pub accounted_processes: Vec<SimulatedAccountedProcess>,
+    pub accounting_buffer_size: u32,
+    pub graphics_processes: Vec<SimulatedProcess>,
+    /// Up to `NVML_NVLINK_MAX_LINKS` links; without any, NvLink queries are not
+    /// supported.
+    pub nvlinks: Vec<SimulatedNvLink>,
+    /// Common ancestors with other GPUs as (device index, `nvmlGpuTopologyLevel_t`).
+    /// Either GPU of a pair may declare it; undeclared pairs meet at
+    /// `NVML_TOPOLOGY_SYSTEM`.
+    pub topology: Vec<(u32, nvmlGpuTopologyLevel_t)>,
+    /// The CPUs closest to the device.
+    pub cpu_affinity: Vec<u32>,
+    /// `nvmlEventType*` bitmask of the events the device can report.
+    pub supported_event_types: u64,
+    /// Served by `nvmlDeviceGetSamples`.
+    pub samples: Vec<SimulatedSample>,
+    /// Served by `nvmlDeviceGetProcessUtilization`.
+    pub process_utilization: Vec<SimulatedProcessUtilization>,
+    /// Without any, vGPU queries are not supported.
+    pub vgpu_types: Vec<SimulatedVgpuType>,
+    /// Ids of the `vgpu_types` an instance can be created of.
+    pub creatable_vgpu_types: Vec<u32>,
+    pub vgpu_instances: Vec<SimulatedVgpuInstance>,
+    /// Encoder capacity left for every codec, in percent.
+    pub encoder_capacity: u32,
+    /// Sessions of host processes; those of `vgpu_instances` are added to them.
+    pub encoder_sessions: Vec<SimulatedEncoderSession>,
+    pub fbc_sessions: Vec<SimulatedFbcSession>,
+    /// Whether vGPUs can migrate to or from the device.
+    pub vgpu_migration: bool,
+    /// Whether the device fell off the bus: queries on its handle then fail with
+    /// `NVML_ERROR_GPU_IS_LOST`.
+    pub lost: bool,
+}
+
+impl SimulatedDevice {
+    /// Returns an idle 16 GB Tesla V100 whose identifiers are derived from `index`.
+    pub fn new(index: u32) -> SimulatedDevice {
+        SimulatedDevice {
+            name: "Tesla V100-SXM2-16GB".to_owned(),
+            uuid: format!("GPU-{:08x}-0000-0000-0000-000000000000", index),
+            serial: format!("03231180{:05}", index),
+            minor_number: index,
+            pci_domain: 0,
+            pci_bus: index + 1,
+            pci_device: 0,
+            pci_device_id: 0x1db1_10de,
+            pci_subsystem_id: 0x1212_10de,
+            pcie_link_generation: 3,
+            pcie_link_width: 16,
+            memory_total: 16 << 30,
+            memory_used: 0,
+            bar1_total: 16 << 30,
+            bar1_used: 0,
+            graphics_clock: 1530,
+            sm_clock: 1530,
+            memory_clock: 877,
+            video_clock: 1372,
+            max_clocks: [1530, 1530, 877, 1372],
+            applications_clocks: [1312, 1312, 877, 1372],
+            default_applications_clocks: [1312, 1312, 877, 1372],
+            applications_clocks_restricted: false,
+            max_customer_boost_clocks: [1530, 1530, 877, 1372],
+            supported_clocks: vec![(877, vec![1530, 1312, 1005, 135])],
+            temperature: 35,
+            gpu_utilization: 0,
+            memory_utilization: 0,
+            encoder_utilization: Some(0),
+            decoder_utilization: Some(0),
+            power_limit: 300_000,
+            default_power_limit: 300_000,
+            power_limit_constraints: (150_000, 300_000),
+            power_usage: 42_000,
+            total_energy_consumption: 0,
+            performance_state: 0,
+            power_management_enabled: true,
+            ecc_enabled: true,
+            ecc_pending: true,
+            ecc_locations: vec![
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L1_CACHE,
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L2_CACHE,
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_DEVICE_MEMORY,
+                nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_REGISTER_FILE,
+            ],
+            ecc_errors: Default::default(),
+            retired_pages: vec![],
+            retired_pages_pending: false,
+            retired_pages_sizing_succeeds: false,
+            throttle_reasons: nvmlClocksThrottleReasonGpuIdle as u64,
+            supported_throttle_reasons: 0x1ff,
+            violation_times: [0; nvmlPerfPolicyType_enum_NVML_PERF_POLICY_COUNT as usize],
+            cuda_compute_capability: (7, 0),
+            compute_processes: vec![],
+            accounting_enabled: false,
+            accounted_processes: vec![],
+            accounting_buffer_size: 4000,
+            graphics_processes: vec![],
+            nvlinks: vec![],
+            topology: vec![],
+            cpu_affinity: (0..16).collect(),
+            supported_event_types: nvmlEventTypeAll as u64,
+            samples: vec![],
+            process_utilization: vec![],
+            vgpu_types: vec![],
+            creatable_vgpu_types: vec![],
+            vgpu_instances: vec![],
+            encoder_capacity: 100,
+            encoder_sessions: vec![],
+            fbc_sessions: vec![],
+            vgpu_migration: true,
+            lost: false,
+        }
+    }
+
+    // The fields `nvmlDeviceGetFieldValues` serves, derived from the rest of the
+    // state; `None` for the others.
+    fn field_value(&mut self, field: c_uint) -> Option<(nvmlValueType_t, nvmlValue_t)> {
+        let uint = |value: u32| {
+            (
+                nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_INT,
+                nvmlValue_t { uiVal: value },
+            )
+        };
+        let ulonglong = |value: u64| {
+            (
+                nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_LONG_LONG,
+                nvmlValue_t { ullVal: value },
+            )
+        };
+        let retired = |pages: &[(nvmlPageRetirementCause_t, u64, u64)], cause| {
+            pages.iter().filter(|(c, _, _)| *c == cause).count() as u64
+        };
+        let ecc_total = |d: &mut SimulatedDevice, error_type, counter_type| {
+            let locations = d.ecc_locations.clone();
+            d.ecc_counters(error_type, counter_type)
+                .map(|counts| ulonglong(locations.iter().map(|l| counts[*l as usize]).sum()))
+        };
+        match field {
+            NVML_FI_DEV_ECC_CURRENT => Some(uint(self.ecc_enabled as u32)),
+            NVML_FI_DEV_ECC_PENDING => Some(uint(self.ecc_pending as u32)),
+            NVML_FI_DEV_ECC_SBE_VOL_TOTAL => ecc_total(
+                self,
+                nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_CORRECTED,
+                nvmlEccCounterType_enum_NVML_VOLATILE_ECC,
+            ),
+            NVML_FI_DEV_ECC_DBE_VOL_TOTAL => ecc_total(
+                self,
+                nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_UNCORRECTED,
+                nvmlEccCounterType_enum_NVML_VOLATILE_ECC,
+            ),
+            NVML_FI_DEV_ECC_SBE_AGG_TOTAL => ecc_total(
+                self,
+                nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_CORRECTED,
+                nvmlEccCounterType_enum_NVML_AGGREGATE_ECC,
+            ),
+            NVML_FI_DEV_ECC_DBE_AGG_TOTAL => ecc_total(
+                self,
+                nvmlMemoryErrorType_enum_NVML_MEMORY_ERROR_TYPE_UNCORRECTED,
+                nvmlEccCounterType_enum_NVML_AGGREGATE_ECC,
+            ),
+            NVML_FI_DEV_RETIRED_SBE => Some(ulonglong(retired(
+                &self.retired_pages,
+                nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_MULTIPLE_SINGLE_BIT_ECC_ERRORS,
+            ))),
+            NVML_FI_DEV_RETIRED_DBE => Some(ulonglong(retired(
+                &self.retired_pages,
+                nvmlPageRetirementCause_enum_NVML_PAGE_RETIREMENT_CAUSE_DOUBLE_BIT_ECC_ERROR,
+            ))),
+            NVML_FI_DEV_RETIRED_PENDING => Some(uint(self.retired_pages_pending as u32)),
+            NVML_FI_DEV_TOTAL_ENERGY_CONSUMPTION => {
+                Some(ulonglong(self.total_energy_consumption))
+            }
+            NVML_FI_DEV_NVLINK_LINK_COUNT if !self.nvlinks.is_empty() => {
+                Some(uint(self.nvlinks.len() as u32))
+            }
+            _ => None,
+        }
+    }
+
+    fn encoder_session_infos(&self) -> Vec<nvmlEncoderSessionInfo_t> {
+        let host = self
+            .encoder_sessions
+            .iter()
+            .map(|s| encoder_session_info(s, 0));
+        let guests = self.vgpu_instances.iter().flat_map(|i| {
+            i.encoder_sessions
+                .iter()
+                .map(move |s| encoder_session_info(s, i.id))
+        });
+        host.chain(guests).collect()
+    }
+
+    fn fbc_session_infos(&self) -> Vec<nvmlFBCSessionInfo_t> {
+        let host = self.fbc_sessions.iter().map(|s| fbc_session_info(s, 0));
+        let guests = self.vgpu_instances.iter().flat_map(|i| {
+            i.fbc_sessions
+                .iter()
+                .map(move |s| fbc_session_info(s, i.id))
+        });
+        host.chain(guests).collect()
+    }
+
+    /// The PCI bus id in NVML's `domain:bus:device.function` format.
+    pub fn pci_bus_id(&self) -> String {
+        format!(
+            "{:08X}:{:02X}:{:02X}.0",
+            self.pci_domain, self.pci_bus, self.pci_device
+        )
+    }
+
+    // The counters of one error and counter type, or `None` while ECC is off or
+    // for out of range arguments.
+    fn ecc_counters(
+        &mut self,
+        error_type: nvmlMemoryErrorType_t,
+        counter_type: nvmlEccCounterType_t,
+    ) -> Option<&mut [u64; nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_COUNT as usize]> {
+        if !self.ecc_enabled {
+            return None;
+        }
+        self.ecc_errors
+            .get_mut(error_type as usize)?
+            .get_mut(counter_type as usize)
+    }
+
+    fn nvlink(&mut self, link: c_uint) -> std::result::Result<&mut SimulatedNvLink, nvmlReturn_t> {
+        if self.nvlinks.is_empty() {
+            return Err(nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED);
+        }
+        self.nvlinks
+            .get_mut(link as usize)
+            .ok_or(nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT)
+    }
+
+    fn nvlink_counter(
+        &mut self,
+        link: c_uint,
+        counter: c_uint,
+    ) -> std::result::Result<&mut SimulatedNvLinkCounter, nvmlReturn_t> {
+        match self
+            .nvlink(link)?
+            .utilization_counters
+            .get_mut(counter as usize)
+        {
+            Some(c) if c.supported => Ok(c),
+            Some(_) => Err(nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED),
+            None => Err(nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT),
+        }
+    }
+
+    fn processes(&self) -> impl Iterator<Item = &SimulatedProcess> {
+        self.compute_processes
+            .iter()
+            .chain(self.graphics_processes.iter())
+    }
+}
+
+impl Default for SimulatedDevice {
+    fn default() -> SimulatedDevice {
+        SimulatedDevice::new(0)
+    }
+}
+
+#[derive(Default)]
+struct EventSetState {
+    // Registered event types by device index.
+    registered: Vec<(usize, u64)>,
+    // Queued (device index, event type, event data) triples.
+    pending: VecDeque<(usize, u64, u64)>,
+}
+
+struct State {
+    // Number of nvmlInit calls not yet matched by nvmlShutdown.
+    init_count: u32,
+    devices: Vec<SimulatedDevice>,
+    // Freed sets stay as `None` so handles are never reused.
+    event_sets: Vec<Option<EventSetState>>,
+    // CPUs threads were pinned to by `nvmlDeviceSetCpuAffinity`.
+    pinned_threads: HashMap<ThreadId, Vec<u32>>,
+    // The vGPU versions guests may use, narrowed by `nvmlSetVgpuVersion`.
+    vgpu_version: (u32, u32),
+}
+
+impl State {
+    fn vgpu_type(&self, id: nvmlVgpuTypeId_t) -> Option<&SimulatedVgpuType> {
+        self.devices
+            .iter()
+            .flat_map(|d| d.vgpu_types.iter())
+            .find(|t| t.id == id)
+    }
+
+    fn vgpu_instance(&self, id: nvmlVgpuInstance_t) -> Option<&SimulatedVgpuInstance> {
+        self.devices
+            .iter()
+            .flat_map(|d| d.vgpu_instances.iter())
+            .find(|i| i.id == id)
+    }
+
+    fn device_pair(&self, a: nvmlDevice_t, b: nvmlDevice_t) -> Option<(usize, usize)> {
+        match (device_index(a), device_index(b)) {
+            (Some(a), Some(b)) if a < self.devices.len() && b < self.devices.len() => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    fn topology_level(&self, a: usize, b: usize) -> nvmlGpuTopologyLevel_t {
+        if a == b {
+            return nvmlGpuLevel_enum_NVML_TOPOLOGY_INTERNAL;
+        }
+        let declared = |from: usize, to: usize| {
+            self.devices[from]
+                .topology
+                .iter()
+                .find(|(index, _)| *index as usize == to)
+                .map(|(_, level)| *level)
+        };
+        declared(a, b)
+            .or_else(|| declared(b, a))
+            .unwrap_or(nvmlGpuLevel_enum_NVML_TOPOLOGY_SYSTEM)
+    }
+
+    // Whether an active NvLink of either device ends at the other.
+    fn nvlinked(&self, a: usize, b: usize) -> bool {
+        let links_to = |from: usize, to: usize| {
+            let bus_id = self.devices[to].pci_bus_id();
+            self.devices[from]
+                .nvlinks
+                .iter()
+                .any(|link| link.active && same_pci_bus_id(&link.remote_pci_bus_id, &bus_id))
+        };
+        links_to(a, b) || links_to(b, a)
+    }
+}
+
+const SUPPORTED_VGPU_VERSIONS: (u32, u32) = (0x0a00, 0x0b00);
+
+/// A [`Backend`] serving a fixed set of [`SimulatedDevice`]s.
+pub struct SimulatedBackend {
+    pub driver_version: String,
+    pub nvml_version: String,
+    pub cuda_driver_version: i32,
+    /// The (min, max) vGPU versions the host driver supports.
+    pub supported_vgpu_versions: (u32, u32),
+    pub units: Vec<SimulatedUnit>,
+    state: Mutex<State>,
+    // Signalled whenever an event is queued.
+    events: Condvar,
+}
+
+impl SimulatedBackend {
+    pub fn new(devices: Vec<SimulatedDevice>) -> SimulatedBackend {
+        SimulatedBackend {
+            driver_version: "418.67".to_owned(),
+            nvml_version: "10.418.67".to_owned(),
+            cuda_driver_version: 10010,
+            supported_vgpu_versions: SUPPORTED_VGPU_VERSIONS,
+            units: vec![],
+            state: Mutex::new(State {
+                init_count: 0,
+                devices,
+                event_sets: vec![],
+                pinned_threads: HashMap::new(),
+                vgpu_version: SUPPORTED_VGPU_VERSIONS,
+            }),
+            events: Condvar::new(),
+        }
+    }
+
+    /// Simulates `count` identical GPUs with distinct identifiers.
+    pub fn with_gpus(count: u32) -> SimulatedBackend {
+        Self::new((0..count).map(SimulatedDevice::new).collect())
+    }
+
+    /// Changes the state of the device at `index`, for example to raise its
+    /// temperature between two queries.
+    pub fn update<F: FnOnce(&mut SimulatedDevice)>(&self, index: u32, f: F) {
+        let mut state = self.state.lock().unwrap();
+        f(&mut state.devices[index as usize]);
+    }
+
+    /// Raises an event of `event_type` on the device at `index`, delivering it to
+    /// every event set the device registered that type on.
+    pub fn emit_event(&self, index: u32, event_type: u64, data: u64) {
+        let mut state = self.state.lock().unwrap();
+        for set in state.event_sets.iter_mut().flatten() {
+            let registered = set
+                .registered
+                .iter()
+                .any(|&(i, types)| i == index as usize && types & event_type != 0);
+            if registered {
+                set.pending.push_back((index as usize, event_type, data));
+            }
+        }
+        self.events.notify_all();
+    }
+
+    /// The CPUs `nvmlDeviceSetCpuAffinity` pinned the calling thread to, if any.
+    pub fn pinned_cpus(&self) -> Option<Vec<u32>> {
+        let state = self.state.lock().unwrap();
+        state
+            .pinned_threads
+            .get(&std::thread::current().id())
+            .cloned()
+    }
+
+    /// Returns whether NVML is currently initialized on this backend.
+    pub fn is_initialized(&self) -> bool {
+        self.state.lock().unwrap().init_count > 0
+    }
+
+    fn with_state<F>(&self, f: F) -> nvmlReturn_t
+    where
+        F: FnOnce(&mut State) -> nvmlReturn_t,
+    {
+        let mut state = self.state.lock().unwrap();
+        if state.init_count == 0 {
+            return nvmlReturn_enum_NVML_ERROR_UNINITIALIZED;
+        }
+        f(&mut state)
+    }
+
+    fn with_device<F>(&self, device: nvmlDevice_t, f: F) -> nvmlReturn_t
+    where
+        F: FnOnce(&mut SimulatedDevice) -> nvmlReturn_t,
+    {
+        self.with_state(|state| match device_index(device) {
+            Some(index) if index < state.devices.len() && state.devices[index].lost => {
+                nvmlReturn_enum_NVML_ERROR_GPU_IS_LOST
+            }
+            Some(index) if index < state.devices.len() => f(&mut state.devices[index]),
+            _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+}
+
+impl SimulatedBackend {
+    fn with_vgpu_type<F>(&self, id: nvmlVgpuTypeId_t, f: F) -> nvmlReturn_t
+    where
+        F: FnOnce(&SimulatedVgpuType) -> nvmlReturn_t,
+    {
+        self.with_state(|state| match state.vgpu_type(id) {
+            Some(vgpu_type) => f(vgpu_type),
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    fn with_vgpu_instance<F>(&self, id: nvmlVgpuInstance_t, f: F) -> nvmlReturn_t
+    where
+        F: FnOnce(&SimulatedVgpuInstance) -> nvmlReturn_t,
+    {
+        self.with_state(|state| match state.vgpu_instance(id) {
+            Some(instance) => f(instance),
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+}
+
+// Simulated device handles are opaque tokens encoding the device index; they are
+// never dereferenced.
+fn device_handle(index: usize) -> nvmlDevice_t {
+    (index + 1) as nvmlDevice_t
+}
+
+fn device_index(device: nvmlDevice_t) -> Option<usize> {
+    (device as usize).checked_sub(1)
+}
+
+fn unit_handle(index: usize) -> nvmlUnit_t {
+    (index + 1) as nvmlUnit_t
+}
+
+fn unit_index(unit: nvmlUnit_t) -> Option<usize> {
+    (unit as usize).checked_sub(1)
+}
+
+fn event_set_handle(index: usize) -> nvmlEventSet_t {
+    (index + 1) as nvmlEventSet_t
+}
+
+fn event_set_index(set: nvmlEventSet_t) -> Option<usize> {
+    (set as usize).checked_sub(1)
+}
+
+unsafe fn write<T>(ptr: *mut T, value: T) -> nvmlReturn_t {
+    if ptr.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    *ptr = value;
+    nvmlReturn_enum_NVML_SUCCESS
+}
+
+// Copies `value` and a NUL terminator into a caller buffer of `length` bytes.
+unsafe fn write_str(value: &str, buf: *mut c_char, length: c_uint) -> nvmlReturn_t {
+    if buf.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    if value.len() >= length as usize {
+        return nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE;
+    }
+    std::ptr::copy_nonoverlapping(value.as_ptr() as *const c_char, buf, value.len());
+    *buf.add(value.len()) = 0;
+    nvmlReturn_enum_NVML_SUCCESS
+}
+
+fn copy_to_array(value: &str, array: &mut [c_char]) {
+    for (dst, src) in array.iter_mut().zip(value.bytes().chain(Some(0))) {
+        *dst = src as c_char;
+    }
+}
+
+// Implements NVML's sizing protocol for list queries: report the required size
+// through `count` and fail if `infos` cannot hold every entry.
+unsafe fn write_list<T>(entries: &[T], count: *mut c_uint, infos: *mut T) -> nvmlReturn_t
+where
+    T: Copy,
+{
+    if count.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    let capacity = *count as usize;
+    *count = entries.len() as c_uint;
+    if capacity < entries.len() {
+        return nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE;
+    }
+    if !entries.is_empty() {
+        if infos.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        std::ptr::copy_nonoverlapping(entries.as_ptr(), infos, entries.len());
+    }
+    nvmlReturn_enum_NVML_SUCCESS
+}
+
+// Serves a session query: like `write_list`, except that a sizing call with a
+// zero count succeeds, as it does for NVML's session queries.
+unsafe fn write_sessions<T>(entries: &[T], count: *mut c_uint, infos: *mut T) -> nvmlReturn_t
+where
+    T: Copy,
+{
+    if count.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    if *count == 0 {
+        *count = entries.len() as c_uint;
+        return nvmlReturn_enum_NVML_SUCCESS;
+    }
+    write_list(entries, count, infos)
+}
+
+fn accounting_stats(process: &SimulatedAccountedProcess) -> nvmlAccountingStats_t {
+    let mut stats: nvmlAccountingStats_t = unsafe { std::mem::zeroed() };
+    stats.gpuUtilization = process.gpu_utilization;
+    stats.memoryUtilization = process.memory_utilization;
+    stats.maxMemoryUsage = process.max_memory_usage;
+    stats.time = process.time;
+    stats.startTime = process.start_time;
+    stats.isRunning = process.running as c_uint;
+    stats
+}
+
+fn encoder_session_info(
+    session: &SimulatedEncoderSession,
+    vgpu_instance: nvmlVgpuInstance_t,
+) -> nvmlEncoderSessionInfo_t {
+    nvmlEncoderSessionInfo_t {
+        sessionId: session.session_id,
+        pid: session.pid,
+        vgpuInstance: vgpu_instance,
+        codecType: session.codec,
+        hResolution: session.resolution.0,
+        vResolution: session.resolution.1,
+        averageFps: session.average_fps,
+        averageLatency: session.average_latency,
+    }
+}
+
+fn fbc_session_info(
+    session: &SimulatedFbcSession,
+    vgpu_instance: nvmlVgpuInstance_t,
+) -> nvmlFBCSessionInfo_t {
+    nvmlFBCSessionInfo_t {
+        sessionId: session.session_id,
+        pid: session.pid,
+        vgpuInstance: vgpu_instance,
+        displayOrdinal: session.display_ordinal,
+        sessionType: session.session_type,
+        sessionFlags: session.flags,
+        hMaxResolution: session.max_resolution.0,
+        vMaxResolution: session.max_resolution.1,
+        hResolution: session.resolution.0,
+        vResolution: session.resolution.1,
+        averageFPS: session.average_fps,
+        averageLatency: session.average_latency,
+    }
+}
+
+// Session count and mean frame rate and latency, as the stats queries report them.
+fn session_stats(sessions: impl Iterator<Item = (u32, u32)>) -> (u32, u32, u32) {
+    let (mut count, mut fps, mut latency) = (0, 0, 0);
+    for (session_fps, session_latency) in sessions {
+        count += 1;
+        fps += session_fps;
+        latency += session_latency;
+    }
+    match count {
+        0 => (0, 0, 0),
+        count => (count, fps / count, latency / count),
+    }
+}
+
+// Serves a sample query whose caller sizes the buffer with a null pointer first,
+// as `nvmlDeviceGetSamples` does.
+unsafe fn write_samples<T>(entries: &[T], count: *mut c_uint, samples: *mut T) -> nvmlReturn_t
+where
+    T: Copy,
+{
+    if count.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    if entries.is_empty() {
+        return nvmlReturn_enum_NVML_ERROR_NOT_FOUND;
+    }
+    if samples.is_null() {
+        *count = entries.len() as c_uint;
+        return nvmlReturn_enum_NVML_SUCCESS;
+    }
+    let filled = entries.len().min(*count as usize);
+    std::ptr::copy_nonoverlapping(entries.as_ptr(), samples, filled);
+    *count = filled as c_uint;
+    nvmlReturn_enum_NVML_SUCCESS
+}
+
+// Serves a metadata query: the first `offset` bytes of `header` followed by
+// `opaque`, provided `size` announces room for both.
+unsafe fn write_metadata<T>(
+    header: &T,
+    offset: usize,
+    opaque: &[u8],
+    buf: *mut T,
+    size: *mut c_uint,
+) -> nvmlReturn_t {
+    if size.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    let required = offset + opaque.len();
+    if (*size as usize) < required {
+        *size = required as c_uint;
+        return nvmlReturn_enum_NVML_ERROR_INSUFFICIENT_SIZE;
+    }
+    if buf.is_null() {
+        return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+    }
+    let buf = buf as *mut u8;
+    std::ptr::copy_nonoverlapping(header as *const T as *const u8, buf, offset);
+    std::ptr::copy_nonoverlapping(opaque.as_ptr(), buf.add(offset), opaque.len());
+    *size = required as c_uint;
+    nvmlReturn_enum_NVML_SUCCESS
+}
+
+// Compares bus ids, accepting the legacy 4 digit domain as NVML does.
+fn same_pci_bus_id(a: &str, b: &str) -> bool {
+    let normalize = |id: &str| {
+        let id = id.to_lowercase();
+        match id.split_once(':') {
+            Some((domain, rest)) => format!("{:0>8}:{}", domain, rest),
+            None => id,
+        }
+    };
+    normalize(a) == normalize(b)
+}
+
+// The CPU timestamp NVML attaches to readings: microseconds since the epoch.
+fn timestamp_us() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_micros() as u64)
+}
+
+// Serves one clock of a table indexed by `nvmlClockType_t`.
+unsafe fn write_clock(
+    clocks: &[u32; 4],
+    type_: nvmlClockType_t,
+    clock: *mut c_uint,
+) -> nvmlReturn_t {
+    match clocks.get(type_ as usize) {
+        Some(&value) => write(clock, value),
+        None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+    }
+}
+
+// Encoder and decoder utilization is sampled over 167 ms, as on real hardware.
+unsafe fn write_codec_utilization(
+    value: Option<u32>,
+    utilization: *mut c_uint,
+    sampling_period_us: *mut c_uint,
+) -> nvmlReturn_t {
+    match value {
+        Some(value) => {
+            let result = write(utilization, value);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return result;
+            }
+            write(sampling_period_us, 167_000)
+        }
+        None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+    }
+}
+
+fn process_infos(processes: &[SimulatedProcess]) -> Vec<nvmlProcessInfo_t> {
+    processes
+        .iter()
+        .map(|process| nvmlProcessInfo_t {
+            pid: process.pid,
+            usedGpuMemory: process.used_gpu_memory,
+        })
+        .collect()
+}
+
+// Parameter names follow the C declarations in nvml-binding.
+#[allow(non_snake_case)]
+impl Backend for SimulatedBackend {
+    unsafe fn nvmlInit_v2(&self) -> nvmlReturn_t {
+        self.state.lock().unwrap().init_count += 1;
+        nvmlReturn_enum_NVML_SUCCESS
+    }
+
+    unsafe fn nvmlShutdown(&self) -> nvmlReturn_t {
+        self.with_state(|state| {
+            state.init_count -= 1;
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlSystemGetDriverVersion(
+        &self,
+        version: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_state(|_| write_str(&self.driver_version, version, length))
+    }
+
+    unsafe fn nvmlSystemGetNVMLVersion(
+        &self,
+        version: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_state(|_| write_str(&self.nvml_version, version, length))
+    }
+
+    unsafe fn nvmlSystemGetCudaDriverVersion(&self, cudaDriverVersion: *mut c_int) -> nvmlReturn_t {
+        self.with_state(|_| write(cudaDriverVersion, self.cuda_driver_version))
+    }
+
+    unsafe fn nvmlSystemGetCudaDriverVersion_v2(
+        &self,
+        cudaDriverVersion: *mut c_int,
+    ) -> nvmlReturn_t {
+        self.nvmlSystemGetCudaDriverVersion(cudaDriverVersion)
+    }
+
+    unsafe fn nvmlSystemGetProcessName(
+        &self,
+        pid: c_uint,
+        name: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let process = state
+                .devices
+                .iter()
+                .flat_map(|device| device.processes())
+                .find(|process| process.pid == pid && !process.name.is_empty());
+            match process {
+                Some(process) => write_str(&process.name, name, length),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            }
+        })
+    }
+
+    unsafe fn nvmlUnitGetCount(&self, unitCount: *mut c_uint) -> nvmlReturn_t {
+        self.with_state(|_| write(unitCount, self.units.len() as c_uint))
+    }
+
+    unsafe fn nvmlUnitGetHandleByIndex(
+        &self,
+        index: c_uint,
+        unit: *mut nvmlUnit_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|_| {
+            if index as usize >= self.units.len() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            write(unit, unit_handle(index as usize))
+        })
+    }
+
+    unsafe fn nvmlUnitGetDevices(
+        &self,
+        unit: nvmlUnit_t,
+        deviceCount: *mut c_uint,
+        devices: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|_| match unit_index(unit).and_then(|i| self.units.get(i)) {
+            Some(unit) => {
+                let handles: Vec<nvmlDevice_t> = unit
+                    .devices
+                    .iter()
+                    .map(|index| device_handle(*index as usize))
+                    .collect();
+                write_list(&handles, deviceCount, devices)
+            }
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetCount_v2(&self, deviceCount: *mut c_uint) -> nvmlReturn_t {
+        self.with_state(|state| write(deviceCount, state.devices.len() as c_uint))
+    }
+
+    unsafe fn nvmlDeviceGetHandleByIndex_v2(
+        &self,
+        index: c_uint,
+        device: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            if index as usize >= state.devices.len() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            write(device, device_handle(index as usize))
+        })
+    }
+
+    unsafe fn nvmlDeviceGetHandleByPciBusId_v2(
+        &self,
+        pciBusId: *const c_char,
+        device: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        if pciBusId.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let bus_id = CStr::from_ptr(pciBusId).to_string_lossy();
+        self.with_state(|state| {
+            match state
+                .devices
+                .iter()
+                .position(|d| same_pci_bus_id(&d.pci_bus_id(), &bus_id))
+            {
+                Some(index) => write(device, device_handle(index)),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetHandleBySerial(
+        &self,
+        serial: *const c_char,
+        device: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        if serial.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let serial = CStr::from_ptr(serial).to_string_lossy();
+        self.with_state(
+            |state| match state.devices.iter().position(|d| d.serial == serial) {
+                Some(index) => write(device, device_handle(index)),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            },
+        )
+    }
+
+    unsafe fn nvmlDeviceGetHandleByUUID(
+        &self,
+        uuid: *const c_char,
+        device: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        if uuid.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let uuid = CStr::from_ptr(uuid).to_string_lossy();
+        self.with_state(
+            |state| match state.devices.iter().position(|d| d.uuid == uuid) {
+                Some(index) => write(device, device_handle(index)),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            },
+        )
+    }
+
+    unsafe fn nvmlDeviceGetIndex(&self, device: nvmlDevice_t, index: *mut c_uint) -> nvmlReturn_t {
+        self.with_device(device, |_| {
+            write(index, device_index(device).unwrap() as c_uint)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetName(
+        &self,
+        device: nvmlDevice_t,
+        name: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write_str(&d.name, name, length))
+    }
+
+    unsafe fn nvmlDeviceGetUUID(
+        &self,
+        device: nvmlDevice_t,
+        uuid: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write_str(&d.uuid, uuid, length))
+    }
+
+    unsafe fn nvmlDeviceGetSerial(
+        &self,
+        device: nvmlDevice_t,
+        serial: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write_str(&d.serial, serial, length))
+    }
+
+    unsafe fn nvmlDeviceGetMinorNumber(
+        &self,
+        device: nvmlDevice_t,
+        minorNumber: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(minorNumber, d.minor_number))
+    }
+
+    unsafe fn nvmlDeviceGetPciInfo_v3(
+        &self,
+        device: nvmlDevice_t,
+        pci: *mut nvmlPciInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let mut info: nvmlPciInfo_t = std::mem::zeroed();
+            copy_to_array(
+                &format!(
+                    "{:04X}:{:02X}:{:02X}.0",
+                    d.pci_domain, d.pci_bus, d.pci_device
+                ),
+                &mut info.busIdLegacy,
+            );
+            copy_to_array(&d.pci_bus_id(), &mut info.busId);
+            info.domain = d.pci_domain;
+            info.bus = d.pci_bus;
+            info.device = d.pci_device;
+            info.pciDeviceId = d.pci_device_id;
+            info.pciSubSystemId = d.pci_subsystem_id;
+            write(pci, info)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetMaxPcieLinkGeneration(
+        &self,
+        device: nvmlDevice_t,
+        maxLinkGen: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(maxLinkGen, d.pcie_link_generation))
+    }
+
+    unsafe fn nvmlDeviceGetMaxPcieLinkWidth(
+        &self,
+        device: nvmlDevice_t,
+        maxLinkWidth: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(maxLinkWidth, d.pcie_link_width))
+    }
+
+    unsafe fn nvmlDeviceGetClockInfo(
+        &self,
+        device: nvmlDevice_t,
+        type_: nvmlClockType_t,
+        clock: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match type_ {
+            nvmlClockType_enum_NVML_CLOCK_GRAPHICS => write(clock, d.graphics_clock),
+            nvmlClockType_enum_NVML_CLOCK_SM => write(clock, d.sm_clock),
+            nvmlClockType_enum_NVML_CLOCK_MEM => write(clock, d.memory_clock),
+            nvmlClockType_enum_NVML_CLOCK_VIDEO => write(clock, d.video_clock),
+            _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetMaxClockInfo(
+        &self,
+        device: nvmlDevice_t,
+        type_: nvmlClockType_t,
+        clock: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write_clock(&d.max_clocks, type_, clock))
+    }
+
+    unsafe fn nvmlDeviceGetApplicationsClock(
+        &self,
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.applications_clocks_restricted {
+                return nvmlReturn_enum_NVML_ERROR_NO_PERMISSION;
+            }
+            write_clock(&d.applications_clocks, clockType, clockMHz)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetDefaultApplicationsClock(
+        &self,
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.applications_clocks_restricted {
+                return nvmlReturn_enum_NVML_ERROR_NO_PERMISSION;
+            }
+            write_clock(&d.default_applications_clocks, clockType, clockMHz)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetMaxCustomerBoostClock(
+        &self,
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_clock(&d.max_customer_boost_clocks, clockType, clockMHz)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetClock(
+        &self,
+        device: nvmlDevice_t,
+        clockType: nvmlClockType_t,
+        clockId: nvmlClockId_t,
+        clockMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match clockId {
+            nvmlClockId_enum_NVML_CLOCK_ID_CURRENT => write_clock(
+                &[d.graphics_clock, d.sm_clock, d.memory_clock, d.video_clock],
+                clockType,
+                clockMHz,
+            ),
+            nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_TARGET
+            | nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_DEFAULT
+                if d.applications_clocks_restricted =>
+            {
+                nvmlReturn_enum_NVML_ERROR_NO_PERMISSION
+            }
+            nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_TARGET => {
+                write_clock(&d.applications_clocks, clockType, clockMHz)
+            }
+            nvmlClockId_enum_NVML_CLOCK_ID_APP_CLOCK_DEFAULT => {
+                write_clock(&d.default_applications_clocks, clockType, clockMHz)
+            }
+            nvmlClockId_enum_NVML_CLOCK_ID_CUSTOMER_BOOST_MAX => {
+                write_clock(&d.max_customer_boost_clocks, clockType, clockMHz)
+            }
+            _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetSupportedMemoryClocks(
+        &self,
+        device: nvmlDevice_t,
+        count: *mut c_uint,
+        clocksMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let memory: Vec<c_uint> = d.supported_clocks.iter().map(|(m, _)| *m).collect();
+            write_list(&memory, count, clocksMHz)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetSupportedGraphicsClocks(
+        &self,
+        device: nvmlDevice_t,
+        memoryClockMHz: c_uint,
+        count: *mut c_uint,
+        clocksMHz: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            match d
+                .supported_clocks
+                .iter()
+                .find(|(m, _)| *m == memoryClockMHz)
+            {
+                Some((_, graphics)) => write_list(graphics, count, clocksMHz),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetTemperature(
+        &self,
+        device: nvmlDevice_t,
+        sensorType: nvmlTemperatureSensors_t,
+        temp: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match sensorType {
+            nvmlTemperatureSensors_enum_NVML_TEMPERATURE_GPU => write(temp, d.temperature),
+            _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetPowerManagementLimit(
+        &self,
+        device: nvmlDevice_t,
+        limit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(limit, d.power_limit))
+    }
+
+    unsafe fn nvmlDeviceGetPowerManagementDefaultLimit(
+        &self,
+        device: nvmlDevice_t,
+        defaultLimit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(defaultLimit, d.default_power_limit))
+    }
+
+    unsafe fn nvmlDeviceGetPowerManagementLimitConstraints(
+        &self,
+        device: nvmlDevice_t,
+        minLimit: *mut c_uint,
+        maxLimit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let result = write(minLimit, d.power_limit_constraints.0);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return result;
+            }
+            write(maxLimit, d.power_limit_constraints.1)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEnforcedPowerLimit(
+        &self,
+        device: nvmlDevice_t,
+        limit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(limit, d.power_limit))
+    }
+
+    unsafe fn nvmlDeviceGetPowerUsage(
+        &self,
+        device: nvmlDevice_t,
+        power: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(power, d.power_usage))
+    }
+
+    unsafe fn nvmlDeviceGetTotalEnergyConsumption(
+        &self,
+        device: nvmlDevice_t,
+        energy: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(energy, d.total_energy_consumption))
+    }
+
+    unsafe fn nvmlDeviceGetPowerState(
+        &self,
+        device: nvmlDevice_t,
+        pState: *mut nvmlPstates_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(pState, d.performance_state))
+    }
+
+    unsafe fn nvmlDeviceGetPowerManagementMode(
+        &self,
+        device: nvmlDevice_t,
+        mode: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(
+                mode,
+                if d.power_management_enabled {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEccMode(
+        &self,
+        device: nvmlDevice_t,
+        current: *mut nvmlEnableState_t,
+        pending: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let state = |enabled| {
+                if enabled {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                }
+            };
+            let result = write(current, state(d.ecc_enabled));
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return result;
+            }
+            write(pending, state(d.ecc_pending))
+        })
+    }
+
+    unsafe fn nvmlDeviceGetTotalEccErrors(
+        &self,
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        eccCounts: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let locations = d.ecc_locations.clone();
+            match d.ecc_counters(errorType, counterType) {
+                Some(counts) => write(
+                    eccCounts,
+                    locations.iter().map(|l| counts[*l as usize]).sum(),
+                ),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetDetailedEccErrors(
+        &self,
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        eccCounts: *mut nvmlEccErrorCounts_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.ecc_counters(errorType, counterType) {
+            Some(counts) => write(
+                eccCounts,
+                nvmlEccErrorCounts_t {
+                    l1Cache: counts[nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L1_CACHE as usize],
+                    l2Cache: counts[nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_L2_CACHE as usize],
+                    deviceMemory: counts
+                        [nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_DEVICE_MEMORY as usize],
+                    registerFile: counts
+                        [nvmlMemoryLocation_enum_NVML_MEMORY_LOCATION_REGISTER_FILE as usize],
+                },
+            ),
+            None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetMemoryErrorCounter(
+        &self,
+        device: nvmlDevice_t,
+        errorType: nvmlMemoryErrorType_t,
+        counterType: nvmlEccCounterType_t,
+        locationType: nvmlMemoryLocation_t,
+        count: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if !d.ecc_locations.contains(&locationType) {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            match d.ecc_counters(errorType, counterType) {
+                Some(counts) => write(count, counts[locationType as usize]),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceClearEccErrorCounts(
+        &self,
+        device: nvmlDevice_t,
+        counterType: nvmlEccCounterType_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            for error_type in 0..2 {
+                match d.ecc_counters(error_type, counterType) {
+                    Some(counts) => *counts = Default::default(),
+                    None => return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+                }
+            }
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetRetiredPages_v2(
+        &self,
+        device: nvmlDevice_t,
+        cause: nvmlPageRetirementCause_t,
+        pageCount: *mut c_uint,
+        addresses: *mut c_ulonglong,
+        timestamps: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let pages: Vec<(u64, u64)> = d
+                .retired_pages
+                .iter()
+                .filter(|page| page.0 == cause)
+                .map(|page| (page.1, page.2))
+                .collect();
+            if d.retired_pages_sizing_succeeds && addresses.is_null() {
+                return write(pageCount, pages.len() as c_uint);
+            }
+            let addresses_list: Vec<u64> = pages.iter().map(|page| page.0).collect();
+            let result = write_list(&addresses_list, pageCount, addresses);
+            if result != nvmlReturn_enum_NVML_SUCCESS || timestamps.is_null() {
+                return result;
+            }
+            for (i, page) in pages.iter().enumerate() {
+                *timestamps.add(i) = page.1;
+            }
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetRetiredPagesPendingStatus(
+        &self,
+        device: nvmlDevice_t,
+        isPending: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(
+                isPending,
+                if d.retired_pages_pending {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetCurrentClocksThrottleReasons(
+        &self,
+        device: nvmlDevice_t,
+        clocksThrottleReasons: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(clocksThrottleReasons, d.throttle_reasons))
+    }
+
+    unsafe fn nvmlDeviceGetSupportedClocksThrottleReasons(
+        &self,
+        device: nvmlDevice_t,
+        supportedClocksThrottleReasons: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(supportedClocksThrottleReasons, d.supported_throttle_reasons)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetViolationStatus(
+        &self,
+        device: nvmlDevice_t,
+        perfPolicyType: nvmlPerfPolicyType_t,
+        violTime: *mut nvmlViolationTime_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            match d.violation_times.get(perfPolicyType as usize) {
+                Some(&violation_time) => write(
+                    violTime,
+                    nvmlViolationTime_t {
+                        referenceTime: timestamp_us(),
+                        violationTime: violation_time,
+                    },
+                ),
+                None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetMemoryInfo(
+        &self,
+        device: nvmlDevice_t,
+        memory: *mut nvmlMemory_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            // u64::MAX marks process memory as unavailable, as NVML does.
+            let used = d.memory_used.max(
+                d.processes()
+                    .filter(|p| p.used_gpu_memory != u64::MAX)
+                    .map(|p| p.used_gpu_memory)
+                    .sum(),
+            );
+            write(
+                memory,
+                nvmlMemory_t {
+                    total: d.memory_total,
+                    free: d.memory_total.saturating_sub(used),
+                    used,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetBAR1MemoryInfo(
+        &self,
+        device: nvmlDevice_t,
+        bar1Memory: *mut nvmlBAR1Memory_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(
+                bar1Memory,
+                nvmlBAR1Memory_t {
+                    bar1Total: d.bar1_total,
+                    bar1Free: d.bar1_total.saturating_sub(d.bar1_used),
+                    bar1Used: d.bar1_used,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetCudaComputeCapability(
+        &self,
+        device: nvmlDevice_t,
+        major: *mut c_int,
+        minor: *mut c_int,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let result = write(major, d.cuda_compute_capability.0);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return result;
+            }
+            write(minor, d.cuda_compute_capability.1)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetComputeRunningProcesses(
+        &self,
+        device: nvmlDevice_t,
+        infoCount: *mut c_uint,
+        infos: *mut nvmlProcessInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_list(&process_infos(&d.compute_processes), infoCount, infos)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetGraphicsRunningProcesses(
+        &self,
+        device: nvmlDevice_t,
+        infoCount: *mut c_uint,
+        infos: *mut nvmlProcessInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_list(&process_infos(&d.graphics_processes), infoCount, infos)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetAccountingMode(
+        &self,
+        device: nvmlDevice_t,
+        mode: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(
+                mode,
+                if d.accounting_enabled {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceSetAccountingMode(
+        &self,
+        device: nvmlDevice_t,
+        mode: nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            d.accounting_enabled = mode == nvmlEnableState_enum_NVML_FEATURE_ENABLED;
+            if !d.accounting_enabled {
+                d.accounted_processes.clear();
+            }
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetAccountingPids(
+        &self,
+        device: nvmlDevice_t,
+        count: *mut c_uint,
+        pids: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if !d.accounting_enabled {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let entries: Vec<c_uint> = d.accounted_processes.iter().map(|p| p.pid).collect();
+            write_list(&entries, count, pids)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetAccountingStats(
+        &self,
+        device: nvmlDevice_t,
+        pid: c_uint,
+        stats: *mut nvmlAccountingStats_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if !d.accounting_enabled {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            // The most recent entry wins when a pid was reused.
+            match d.accounted_processes.iter().rev().find(|p| p.pid == pid) {
+                Some(p) => write(stats, accounting_stats(p)),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetAccountingBufferSize(
+        &self,
+        device: nvmlDevice_t,
+        bufferSize: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if !d.accounting_enabled {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            write(bufferSize, d.accounting_buffer_size)
+        })
+    }
+
+    unsafe fn nvmlDeviceClearAccountingPids(&self, device: nvmlDevice_t) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            d.accounted_processes.retain(|p| p.running);
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetSupportedEventTypes(
+        &self,
+        device: nvmlDevice_t,
+        eventTypes: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| write(eventTypes, d.supported_event_types))
+    }
+
+    unsafe fn nvmlDeviceGetCpuAffinity(
+        &self,
+        device: nvmlDevice_t,
+        cpuSetSize: c_uint,
+        cpuSet: *mut c_ulong,
+    ) -> nvmlReturn_t {
+        if cpuSet.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        self.with_device(device, |d| {
+            let words = std::slice::from_raw_parts_mut(cpuSet, cpuSetSize as usize);
+            words.iter_mut().for_each(|word| *word = 0);
+            // CPUs past the end of the caller's set are left out, as NVML does.
+            for cpu in d.cpu_affinity.iter() {
+                if let Some(word) = words.get_mut((cpu / c_ulong::BITS) as usize) {
+                    *word |= 1 << (cpu % c_ulong::BITS);
+                }
+            }
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceSetCpuAffinity(&self, device: nvmlDevice_t) -> nvmlReturn_t {
+        self.with_state(|state| match state.device_pair(device, device) {
+            Some((index, _)) => {
+                let cpus = state.devices[index].cpu_affinity.clone();
+                state
+                    .pinned_threads
+                    .insert(std::thread::current().id(), cpus);
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceClearCpuAffinity(&self, device: nvmlDevice_t) -> nvmlReturn_t {
+        self.with_state(|state| match state.device_pair(device, device) {
+            Some(_) => {
+                state.pinned_threads.remove(&std::thread::current().id());
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlSystemGetTopologyGpuSet(
+        &self,
+        cpuNumber: c_uint,
+        count: *mut c_uint,
+        deviceArray: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let devices: Vec<nvmlDevice_t> = (0..state.devices.len())
+                .filter(|index| state.devices[*index].cpu_affinity.contains(&cpuNumber))
+                .map(device_handle)
+                .collect();
+            write_list(&devices, count, deviceArray)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetTopologyCommonAncestor(
+        &self,
+        device1: nvmlDevice_t,
+        device2: nvmlDevice_t,
+        pathInfo: *mut nvmlGpuTopologyLevel_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| match state.device_pair(device1, device2) {
+            Some((a, b)) => write(pathInfo, state.topology_level(a, b)),
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetTopologyNearestGpus(
+        &self,
+        device: nvmlDevice_t,
+        level: nvmlGpuTopologyLevel_t,
+        count: *mut c_uint,
+        deviceArray: *mut nvmlDevice_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| match state.device_pair(device, device) {
+            Some((a, _)) => {
+                let nearest: Vec<nvmlDevice_t> = (0..state.devices.len())
+                    .filter(|b| *b != a && state.topology_level(a, *b) <= level)
+                    .map(device_handle)
+                    .collect();
+                write_list(&nearest, count, deviceArray)
+            }
+            None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    // Read, write and atomics work up to a shared host bridge or over NvLink.
+    unsafe fn nvmlDeviceGetP2PStatus(
+        &self,
+        device1: nvmlDevice_t,
+        device2: nvmlDevice_t,
+        p2pIndex: nvmlGpuP2PCapsIndex_t,
+        p2pStatus: *mut nvmlGpuP2PStatus_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let (a, b) = match state.device_pair(device1, device2) {
+                Some(pair) => pair,
+                None => return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            };
+            let nvlinked = state.nvlinked(a, b);
+            let status = match p2pIndex {
+                nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_READ
+                | nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_WRITE
+                | nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_ATOMICS => {
+                    if nvlinked
+                        || state.topology_level(a, b) <= nvmlGpuLevel_enum_NVML_TOPOLOGY_HOSTBRIDGE
+                    {
+                        nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_OK
+                    } else {
+                        nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_IOH_TOPOLOGY_NOT_SUPPORTED
+                    }
+                }
+                nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_NVLINK if nvlinked => {
+                    nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_OK
+                }
+                _ => nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_NOT_SUPPORTED,
+            };
+            write(p2pStatus, status)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkState(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        isActive: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(nvlink) => write(
+                isActive,
+                if nvlink.active {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                },
+            ),
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkVersion(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        version: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(nvlink) => match nvlink.version {
+                Some(value) => write(version, value),
+                None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            },
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkCapability(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        capability: nvmlNvLinkCapability_t,
+        capResult: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(_) if capability >= nvmlNvLinkCapability_enum_NVML_NVLINK_CAP_COUNT => {
+                nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT
+            }
+            Ok(nvlink) => write(capResult, (nvlink.capabilities >> capability) & 1),
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkRemotePciInfo_v2(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        pci: *mut nvmlPciInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(nvlink) => {
+                let mut info: nvmlPciInfo_t = std::mem::zeroed();
+                copy_to_array(&nvlink.remote_pci_bus_id, &mut info.busId);
+                info.pciDeviceId = nvlink.remote_pci_device_id;
+                write(pci, info)
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkErrorCounter(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: nvmlNvLinkErrorCounter_t,
+        counterValue: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(nvlink) => match nvlink.error_counters.get(counter as usize) {
+                Some(&value) => write(counterValue, value),
+                None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            },
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceResetNvLinkErrorCounters(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink(link) {
+            Ok(nvlink) => {
+                nvlink.error_counters = Default::default();
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceSetNvLinkUtilizationControl(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        control: *mut nvmlNvLinkUtilizationControl_t,
+        reset: c_uint,
+    ) -> nvmlReturn_t {
+        if control.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let control = *control;
+        if control.units >= nvmlNvLinkUtilizationCountUnits_enum_NVML_NVLINK_COUNTER_UNIT_COUNT {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        self.with_device(device, |d| match d.nvlink_counter(link, counter) {
+            Ok(c) => {
+                c.units = control.units;
+                c.packet_filter = control.pktfilter;
+                if reset != 0 {
+                    c.rx = 0;
+                    c.tx = 0;
+                }
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkUtilizationControl(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        control: *mut nvmlNvLinkUtilizationControl_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink_counter(link, counter) {
+            Ok(c) => write(
+                control,
+                nvmlNvLinkUtilizationControl_t {
+                    units: c.units,
+                    pktfilter: c.packet_filter,
+                },
+            ),
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetNvLinkUtilizationCounter(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        rxcounter: *mut c_ulonglong,
+        txcounter: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink_counter(link, counter) {
+            Ok(c) => {
+                let result = write(rxcounter, c.rx);
+                if result != nvmlReturn_enum_NVML_SUCCESS {
+                    return result;
+                }
+                write(txcounter, c.tx)
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceFreezeNvLinkUtilizationCounter(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+        freeze: nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink_counter(link, counter) {
+            Ok(c) => {
+                c.frozen = freeze == nvmlEnableState_enum_NVML_FEATURE_ENABLED;
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlDeviceResetNvLinkUtilizationCounter(
+        &self,
+        device: nvmlDevice_t,
+        link: c_uint,
+        counter: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match d.nvlink_counter(link, counter) {
+            Ok(c) => {
+                c.rx = 0;
+                c.tx = 0;
+                nvmlReturn_enum_NVML_SUCCESS
+            }
+            Err(result) => result,
+        })
+    }
+
+    unsafe fn nvmlEventSetCreate(&self, set: *mut nvmlEventSet_t) -> nvmlReturn_t {
+        self.with_state(|state| {
+            state.event_sets.push(Some(EventSetState::default()));
+            write(set, event_set_handle(state.event_sets.len() - 1))
+        })
+    }
+
+    unsafe fn nvmlDeviceRegisterEvents(
+        &self,
+        device: nvmlDevice_t,
+        eventTypes: c_ulonglong,
+        set: nvmlEventSet_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let index = match device_index(device) {
+                Some(index) if index < state.devices.len() => index,
+                _ => return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            };
+            if eventTypes & !state.devices[index].supported_event_types != 0 {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            match event_set_index(set).and_then(|i| state.event_sets.get_mut(i)) {
+                Some(Some(set)) => {
+                    set.registered.push((index, eventTypes));
+                    nvmlReturn_enum_NVML_SUCCESS
+                }
+                _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            }
+        })
+    }
+
+    unsafe fn nvmlEventSetWait(
+        &self,
+        set: nvmlEventSet_t,
+        data: *mut nvmlEventData_t,
+        timeoutms: c_uint,
+    ) -> nvmlReturn_t {
+        let deadline = Instant::now() + Duration::from_millis(timeoutms as u64);
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.init_count == 0 {
+                return nvmlReturn_enum_NVML_ERROR_UNINITIALIZED;
+            }
+            let pending = match event_set_index(set).and_then(|i| state.event_sets.get_mut(i)) {
+                Some(Some(set)) => &mut set.pending,
+                _ => return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            };
+            if let Some((index, event_type, event_data)) = pending.pop_front() {
+                let event = nvmlEventData_t {
+                    device: device_handle(index),
+                    eventType: event_type,
+                    eventData: event_data,
+                };
+                return write(data, event);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return nvmlReturn_enum_NVML_ERROR_TIMEOUT;
+            }
+            state = self.events.wait_timeout(state, deadline - now).unwrap().0;
+        }
+    }
+
+    unsafe fn nvmlEventSetFree(&self, set: nvmlEventSet_t) -> nvmlReturn_t {
+        self.with_state(|state| {
+            match event_set_index(set).and_then(|i| state.event_sets.get_mut(i)) {
+                Some(set @ Some(_)) => {
+                    *set = None;
+                    nvmlReturn_enum_NVML_SUCCESS
+                }
+                _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetUtilizationRates(
+        &self,
+        device: nvmlDevice_t,
+        utilization: *mut nvmlUtilization_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write(
+                utilization,
+                nvmlUtilization_t {
+                    gpu: d.gpu_utilization,
+                    memory: d.memory_utilization,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEncoderUtilization(
+        &self,
+        device: nvmlDevice_t,
+        utilization: *mut c_uint,
+        samplingPeriodUs: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_codec_utilization(d.encoder_utilization, utilization, samplingPeriodUs)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetDecoderUtilization(
+        &self,
+        device: nvmlDevice_t,
+        utilization: *mut c_uint,
+        samplingPeriodUs: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_codec_utilization(d.decoder_utilization, utilization, samplingPeriodUs)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetSupportedVgpus(
+        &self,
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuTypeIds: *mut nvmlVgpuTypeId_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let ids: Vec<nvmlVgpuTypeId_t> = d.vgpu_types.iter().map(|t| t.id).collect();
+            write_list(&ids, vgpuCount, vgpuTypeIds)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetCreatableVgpus(
+        &self,
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuTypeIds: *mut nvmlVgpuTypeId_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            write_list(&d.creatable_vgpu_types, vgpuCount, vgpuTypeIds)
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetClass(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeClass: *mut c_char,
+        size: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            if size.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            write_str(&t.class, vgpuTypeClass, *size)
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetName(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeName: *mut c_char,
+        size: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            if size.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            write_str(&t.name, vgpuTypeName, *size)
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetDeviceID(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        deviceID: *mut c_ulonglong,
+        subsystemID: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| match write(deviceID, t.device_id) {
+            nvmlReturn_enum_NVML_SUCCESS => write(subsystemID, t.subsystem_id),
+            result => result,
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetFramebufferSize(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        fbSize: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| write(fbSize, t.framebuffer_size))
+    }
+
+    unsafe fn nvmlVgpuTypeGetNumDisplayHeads(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        numDisplayHeads: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            write(numDisplayHeads, t.resolutions.len() as c_uint)
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetResolution(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        displayIndex: c_uint,
+        xdim: *mut c_uint,
+        ydim: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            match t.resolutions.get(displayIndex as usize) {
+                Some(&(x, y)) => match write(xdim, x) {
+                    nvmlReturn_enum_NVML_SUCCESS => write(ydim, y),
+                    result => result,
+                },
+                None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            }
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetLicense(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuTypeLicenseString: *mut c_char,
+        size: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            write_str(&t.license, vgpuTypeLicenseString, size)
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetFrameRateLimit(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        frameRateLimit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| match t.frame_rate_limit {
+            Some(limit) => write(frameRateLimit, limit),
+            None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetMaxInstances(
+        &self,
+        device: nvmlDevice_t,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuInstanceCount: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            match d.vgpu_types.iter().find(|t| t.id == vgpuTypeId) {
+                Some(t) => write(vgpuInstanceCount, t.max_instances),
+                None => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            }
+        })
+    }
+
+    unsafe fn nvmlVgpuTypeGetMaxInstancesPerVm(
+        &self,
+        vgpuTypeId: nvmlVgpuTypeId_t,
+        vgpuInstanceCountPerVm: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_type(vgpuTypeId, |t| {
+            write(vgpuInstanceCountPerVm, t.max_instances_per_vm)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetActiveVgpus(
+        &self,
+        device: nvmlDevice_t,
+        vgpuCount: *mut c_uint,
+        vgpuInstances: *mut nvmlVgpuInstance_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let ids: Vec<nvmlVgpuInstance_t> = d.vgpu_instances.iter().map(|i| i.id).collect();
+            write_list(&ids, vgpuCount, vgpuInstances)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetVmID(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        vmId: *mut c_char,
+        size: c_uint,
+        vmIdType: *mut nvmlVgpuVmIdType_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| match write(vmIdType, i.vm_id_type) {
+            nvmlReturn_enum_NVML_SUCCESS => write_str(&i.vm_id, vmId, size),
+            result => result,
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetUUID(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        uuid: *mut c_char,
+        size: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| write_str(&i.uuid, uuid, size))
+    }
+
+    unsafe fn nvmlVgpuInstanceGetVmDriverVersion(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        version: *mut c_char,
+        length: c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            write_str(&i.vm_driver_version, version, length)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetFbUsage(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        fbUsage: *mut c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| write(fbUsage, i.fb_usage))
+    }
+
+    unsafe fn nvmlVgpuInstanceGetLicenseStatus(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        licensed: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| write(licensed, i.licensed as c_uint))
+    }
+
+    unsafe fn nvmlVgpuInstanceGetType(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        vgpuTypeId: *mut nvmlVgpuTypeId_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| write(vgpuTypeId, i.vgpu_type))
+    }
+
+    unsafe fn nvmlVgpuInstanceGetFrameRateLimit(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        frameRateLimit: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| match i.frame_rate_limit {
+            Some(limit) => write(frameRateLimit, limit),
+            None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetEncoderCapacity(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        encoderCapacity: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| write(encoderCapacity, i.encoder_capacity))
+    }
+
+    unsafe fn nvmlVgpuInstanceGetEncoderStats(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        averageFps: *mut c_uint,
+        averageLatency: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            if sessionCount.is_null() || averageFps.is_null() || averageLatency.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let (count, fps, latency) = session_stats(
+                i.encoder_sessions
+                    .iter()
+                    .map(|s| (s.average_fps, s.average_latency)),
+            );
+            *sessionCount = count;
+            *averageFps = fps;
+            *averageLatency = latency;
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetEncoderSessions(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlEncoderSessionInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            let sessions: Vec<nvmlEncoderSessionInfo_t> = i
+                .encoder_sessions
+                .iter()
+                .map(|s| encoder_session_info(s, i.id))
+                .collect();
+            write_sessions(&sessions, sessionCount, sessionInfo)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetFBCStats(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        fbcStats: *mut nvmlFBCStats_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            let (count, fps, latency) = session_stats(
+                i.fbc_sessions
+                    .iter()
+                    .map(|s| (s.average_fps, s.average_latency)),
+            );
+            write(
+                fbcStats,
+                nvmlFBCStats_t {
+                    sessionsCount: count,
+                    averageFPS: fps,
+                    averageLatency: latency,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetFBCSessions(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlFBCSessionInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            let sessions: Vec<nvmlFBCSessionInfo_t> = i
+                .fbc_sessions
+                .iter()
+                .map(|s| fbc_session_info(s, i.id))
+                .collect();
+            write_sessions(&sessions, sessionCount, sessionInfo)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetAccountingMode(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        mode: *mut nvmlEnableState_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            write(
+                mode,
+                if i.accounting_enabled {
+                    nvmlEnableState_enum_NVML_FEATURE_ENABLED
+                } else {
+                    nvmlEnableState_enum_NVML_FEATURE_DISABLED
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetAccountingPids(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        count: *mut c_uint,
+        pids: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            if !i.accounting_enabled {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let entries: Vec<c_uint> = i.accounted_processes.iter().map(|p| p.pid).collect();
+            write_list(&entries, count, pids)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetAccountingStats(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        pid: c_uint,
+        stats: *mut nvmlAccountingStats_t,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            if !i.accounting_enabled {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            match i.accounted_processes.iter().rev().find(|p| p.pid == pid) {
+                Some(p) => write(stats, accounting_stats(p)),
+                None => nvmlReturn_enum_NVML_ERROR_NOT_FOUND,
+            }
+        })
+    }
+
+    unsafe fn nvmlDeviceGetVgpuUtilization(
+        &self,
+        device: nvmlDevice_t,
+        lastSeenTimeStamp: c_ulonglong,
+        sampleValType: *mut nvmlValueType_t,
+        vgpuInstanceSamplesCount: *mut c_uint,
+        utilizationSamples: *mut nvmlVgpuInstanceUtilizationSample_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            if sampleValType.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let newer: Vec<nvmlVgpuInstanceUtilizationSample_t> = d
+                .vgpu_instances
+                .iter()
+                .flat_map(|i| i.utilization.iter().map(move |u| (i.id, u)))
+                .filter(|(_, u)| u.timestamp > lastSeenTimeStamp)
+                .map(|(id, u)| nvmlVgpuInstanceUtilizationSample_t {
+                    vgpuInstance: id,
+                    timeStamp: u.timestamp,
+                    smUtil: nvmlValue_t { uiVal: u.sm },
+                    memUtil: nvmlValue_t { uiVal: u.memory },
+                    encUtil: nvmlValue_t { uiVal: u.encoder },
+                    decUtil: nvmlValue_t { uiVal: u.decoder },
+                })
+                .collect();
+            *sampleValType = nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_INT;
+            write_samples(&newer, vgpuInstanceSamplesCount, utilizationSamples)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetVgpuProcessUtilization(
+        &self,
+        device: nvmlDevice_t,
+        lastSeenTimeStamp: c_ulonglong,
+        vgpuProcessSamplesCount: *mut c_uint,
+        utilizationSamples: *mut nvmlVgpuProcessUtilizationSample_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let mut newer = vec![];
+            for i in d.vgpu_instances.iter() {
+                for u in i.process_utilization.iter() {
+                    if u.timestamp <= lastSeenTimeStamp {
+                        continue;
+                    }
+                    let mut sample: nvmlVgpuProcessUtilizationSample_t = std::mem::zeroed();
+                    sample.vgpuInstance = i.id;
+                    sample.pid = u.pid;
+                    if let Some(p) = i.processes.iter().find(|p| p.pid == u.pid) {
+                        copy_to_array(&p.name, &mut sample.processName);
+                    }
+                    sample.timeStamp = u.timestamp;
+                    sample.smUtil = u.sm;
+                    sample.memUtil = u.memory;
+                    sample.encUtil = u.encoder;
+                    sample.decUtil = u.decoder;
+                    newer.push(sample);
+                }
+            }
+            write_samples(&newer, vgpuProcessSamplesCount, utilizationSamples)
+        })
+    }
+
+    unsafe fn nvmlVgpuInstanceGetMetadata(
+        &self,
+        vgpuInstance: nvmlVgpuInstance_t,
+        vgpuMetadata: *mut nvmlVgpuMetadata_t,
+        bufferSize: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_vgpu_instance(vgpuInstance, |i| {
+            let mut metadata: nvmlVgpuMetadata_t = std::mem::zeroed();
+            metadata.version = 1;
+            if !i.vm_driver_version.is_empty() {
+                metadata.guestInfoState =
+                    nvmlVgpuGuestInfoState_enum_NVML_VGPU_INSTANCE_GUEST_INFO_STATE_INITIALIZED;
+                copy_to_array(&i.vm_driver_version, &mut metadata.guestDriverVersion);
+                metadata.guestVgpuVersion = i.guest_vgpu_version;
+            }
+            copy_to_array(&self.driver_version, &mut metadata.hostDriverVersion);
+            // The opaque state a real driver saves is modelled by the uuid.
+            metadata.opaqueDataSize = i.uuid.len() as c_uint;
+            write_metadata(
+                &metadata,
+                std::mem::offset_of!(nvmlVgpuMetadata_t, opaqueData),
+                i.uuid.as_bytes(),
+                vgpuMetadata,
+                bufferSize,
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetVgpuMetadata(
+        &self,
+        device: nvmlDevice_t,
+        pgpuMetadata: *mut nvmlVgpuPgpuMetadata_t,
+        bufferSize: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let d = match device_index(device) {
+                Some(index) if index < state.devices.len() => &state.devices[index],
+                _ => return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+            };
+            if d.vgpu_types.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            let mut metadata: nvmlVgpuPgpuMetadata_t = std::mem::zeroed();
+            metadata.version = 1;
+            copy_to_array(&self.driver_version, &mut metadata.hostDriverVersion);
+            metadata.pgpuVirtualizationCaps = if d.vgpu_migration {
+                NVML_VGPU_PGPU_VIRTUALIZATION_CAP_MIGRATION_YES
+            } else {
+                NVML_VGPU_PGPU_VIRTUALIZATION_CAP_MIGRATION_NO
+            };
+            // The range narrowed by `nvmlSetVgpuVersion`, as drivers report it.
+            metadata.hostSupportedVgpuRange = nvmlVgpuVersion_t {
+                minVersion: state.vgpu_version.0,
+                maxVersion: state.vgpu_version.1,
+            };
+            metadata.opaqueDataSize = d.uuid.len() as c_uint;
+            write_metadata(
+                &metadata,
+                std::mem::offset_of!(nvmlVgpuPgpuMetadata_t, opaqueData),
+                d.uuid.as_bytes(),
+                pgpuMetadata,
+                bufferSize,
+            )
+        })
+    }
+
+    unsafe fn nvmlGetVgpuCompatibility(
+        &self,
+        vgpuMetadata: *mut nvmlVgpuMetadata_t,
+        pgpuMetadata: *mut nvmlVgpuPgpuMetadata_t,
+        compatibilityInfo: *mut nvmlVgpuPgpuCompatibility_t,
+    ) -> nvmlReturn_t {
+        if vgpuMetadata.is_null() || pgpuMetadata.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let (vgpu, pgpu) = (&*vgpuMetadata, &*pgpuMetadata);
+        let range = pgpu.hostSupportedVgpuRange;
+        let guest_loaded = vgpu.guestInfoState
+            == nvmlVgpuGuestInfoState_enum_NVML_VGPU_INSTANCE_GUEST_INFO_STATE_INITIALIZED;
+        let same_host_driver = CStr::from_ptr(vgpu.hostDriverVersion.as_ptr())
+            == CStr::from_ptr(pgpu.hostDriverVersion.as_ptr());
+        // Stricter than real drivers: a host driver upgrade only allows a cold
+        // migration.
+        let (vm, limit) =
+            if pgpu.pgpuVirtualizationCaps != NVML_VGPU_PGPU_VIRTUALIZATION_CAP_MIGRATION_YES {
+                (
+                    nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_NONE,
+                    nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GPU,
+                )
+            } else if guest_loaded
+                && (vgpu.guestVgpuVersion < range.minVersion
+                    || vgpu.guestVgpuVersion > range.maxVersion)
+            {
+                (
+                nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_NONE,
+                nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_GUEST_DRIVER,
+            )
+            } else if !same_host_driver {
+                (
+                nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_COLD,
+                nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_HOST_DRIVER,
+            )
+            } else {
+                (
+                    nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_COLD
+                        | nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_HIBERNATE
+                        | nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_SLEEP
+                        | nvmlVgpuVmCompatibility_enum_NVML_VGPU_VM_COMPATIBILITY_LIVE,
+                    nvmlVgpuPgpuCompatibilityLimitCode_enum_NVML_VGPU_COMPATIBILITY_LIMIT_NONE,
+                )
+            };
+        self.with_state(|_| {
+            write(
+                compatibilityInfo,
+                nvmlVgpuPgpuCompatibility_t {
+                    vgpuVmCompatibility: vm,
+                    compatibilityLimitCode: limit,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlGetVgpuVersion(
+        &self,
+        supported: *mut nvmlVgpuVersion_t,
+        current: *mut nvmlVgpuVersion_t,
+    ) -> nvmlReturn_t {
+        self.with_state(|state| {
+            let (min, max) = self.supported_vgpu_versions;
+            match write(
+                supported,
+                nvmlVgpuVersion_t {
+                    minVersion: min,
+                    maxVersion: max,
+                },
+            ) {
+                nvmlReturn_enum_NVML_SUCCESS => {}
+                result => return result,
+            }
+            let (min, max) = state.vgpu_version;
+            write(
+                current,
+                nvmlVgpuVersion_t {
+                    minVersion: min,
+                    maxVersion: max,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlSetVgpuVersion(&self, vgpuVersion: *mut nvmlVgpuVersion_t) -> nvmlReturn_t {
+        if vgpuVersion.is_null() {
+            return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+        }
+        let version = *vgpuVersion;
+        self.with_state(|state| {
+            if state.devices.iter().any(|d| !d.vgpu_instances.is_empty()) {
+                return nvmlReturn_enum_NVML_ERROR_IN_USE;
+            }
+            let (min, max) = self.supported_vgpu_versions;
+            if version.minVersion > version.maxVersion
+                || version.minVersion < min
+                || version.maxVersion > max
+            {
+                return nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED;
+            }
+            state.vgpu_version = (version.minVersion, version.maxVersion);
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEncoderCapacity(
+        &self,
+        device: nvmlDevice_t,
+        encoderQueryType: nvmlEncoderType_t,
+        encoderCapacity: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| match encoderQueryType {
+            nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_H264
+            | nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_HEVC => {
+                write(encoderCapacity, d.encoder_capacity)
+            }
+            _ => nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT,
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEncoderStats(
+        &self,
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        averageFps: *mut c_uint,
+        averageLatency: *mut c_uint,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if sessionCount.is_null() || averageFps.is_null() || averageLatency.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let (count, fps, latency) = session_stats(
+                d.encoder_session_infos()
+                    .iter()
+                    .map(|s| (s.averageFps, s.averageLatency)),
+            );
+            *sessionCount = count;
+            *averageFps = fps;
+            *averageLatency = latency;
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetEncoderSessions(
+        &self,
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        sessionInfos: *mut nvmlEncoderSessionInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_sessions(&d.encoder_session_infos(), sessionCount, sessionInfos)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetFBCStats(
+        &self,
+        device: nvmlDevice_t,
+        fbcStats: *mut nvmlFBCStats_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let (count, fps, latency) = session_stats(
+                d.fbc_session_infos()
+                    .iter()
+                    .map(|s| (s.averageFPS, s.averageLatency)),
+            );
+            write(
+                fbcStats,
+                nvmlFBCStats_t {
+                    sessionsCount: count,
+                    averageFPS: fps,
+                    averageLatency: latency,
+                },
+            )
+        })
+    }
+
+    unsafe fn nvmlDeviceGetFBCSessions(
+        &self,
+        device: nvmlDevice_t,
+        sessionCount: *mut c_uint,
+        sessionInfo: *mut nvmlFBCSessionInfo_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            write_sessions(&d.fbc_session_infos(), sessionCount, sessionInfo)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetFieldValues(
+        &self,
+        device: nvmlDevice_t,
+        valuesCount: c_int,
+        values: *mut nvmlFieldValue_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if valuesCount <= 0 || values.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let timestamp = timestamp_us() as i64;
+            for value in std::slice::from_raw_parts_mut(values, valuesCount as usize) {
+                value.timestamp = timestamp;
+                value.latencyUsec = 0;
+                value.nvmlReturn = match d.field_value(value.fieldId) {
+                    Some((value_type, v)) => {
+                        value.valueType = value_type;
+                        value.value = v;
+                        nvmlReturn_enum_NVML_SUCCESS
+                    }
+                    None => nvmlReturn_enum_NVML_ERROR_NOT_SUPPORTED,
+                };
+            }
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+
+    unsafe fn nvmlDeviceGetProcessUtilization(
+        &self,
+        device: nvmlDevice_t,
+        utilization: *mut nvmlProcessUtilizationSample_t,
+        processSamplesCount: *mut c_uint,
+        lastSeenTimeStamp: c_ulonglong,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            let entries: Vec<nvmlProcessUtilizationSample_t> = d
+                .process_utilization
+                .iter()
+                .filter(|sample| sample.timestamp > lastSeenTimeStamp)
+                .map(|sample| nvmlProcessUtilizationSample_t {
+                    pid: sample.pid,
+                    timeStamp: sample.timestamp,
+                    smUtil: sample.sm,
+                    memUtil: sample.memory,
+                    encUtil: sample.encoder,
+                    decUtil: sample.decoder,
+                })
+                .collect();
+            if entries.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_FOUND;
+            }
+            write_list(&entries, processSamplesCount, utilization)
+        })
+    }
+
+    unsafe fn nvmlDeviceGetSamples(
+        &self,
+        device: nvmlDevice_t,
+        type_: nvmlSamplingType_t,
+        lastSeenTimeStamp: c_ulonglong,
+        sampleValType: *mut nvmlValueType_t,
+        sampleCount: *mut c_uint,
+        samples: *mut nvmlSample_t,
+    ) -> nvmlReturn_t {
+        self.with_device(device, |d| {
+            if sampleCount.is_null() || sampleValType.is_null() {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let newer: Vec<nvmlSample_t> = d
+                .samples
+                .iter()
+                .filter(|s| s.sampling_type == type_ && s.timestamp > lastSeenTimeStamp)
+                .map(|s| nvmlSample_t {
+                    timeStamp: s.timestamp,
+                    sampleValue: nvmlValue_t { uiVal: s.value },
+                })
+                .collect();
+            if newer.is_empty() {
+                return nvmlReturn_enum_NVML_ERROR_NOT_FOUND;
+            }
+            *sampleValType = nvmlValueType_enum_NVML_VALUE_TYPE_UNSIGNED_INT;
+            // Sizing call: report how many samples can be queried.
+            if samples.is_null() {
+                *sampleCount = newer.len() as c_uint;
+                return nvmlReturn_enum_NVML_SUCCESS;
+            }
+            if *sampleCount == 0 {
+                return nvmlReturn_enum_NVML_ERROR_INVALID_ARGUMENT;
+            }
+            let filled = newer.len().min(*sampleCount as usize);
+            std::ptr::copy_nonoverlapping(newer.as_ptr(), samples, filled);
+            *sampleCount = filled as c_uint;
+            nvmlReturn_enum_NVML_SUCCESS
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Device, DeviceSensorType, Handler, NVML};
+    use std::sync::Arc;
+
+    #[test]
+    fn devices_are_served_from_the_simulation() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(2));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        assert_eq!(nvml.device_count().unwrap(), 2);
+        assert_eq!(nvml.driver_version().unwrap(), "418.67");
+
+        let device = Device::new(&nvml, 1).unwrap();
+        assert_eq!(device.uuid, "GPU-00000001-0000-0000-0000-000000000000");
+        assert_eq!(device.path, "/dev/nvidia1");
+        assert_eq!(device.pci.bus_id, "00000000:02:00.0");
+        assert_eq!(device.memory, 16 << 30);
+        assert_eq!(device.clocks.cores, 1530);
+        assert_eq!(device.cuda_compute_capability.major, 7);
+
+        backend.update(1, |d| d.temperature = 82);
+        assert_eq!(device.get_temperature(DeviceSensorType::GPU).unwrap(), 82);
+    }
+
+    #[test]
+    fn shutdown_on_drop() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(1));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        // A Handler cannot outlive NVML, so go through the raw handle.
+        let dev = Handler::new(&nvml, 0).unwrap().dev;
+        drop(nvml);
+        assert!(!backend.is_initialized());
+        let mut name = [0 as c_char; NVML_DEVICE_NAME_BUFFER_SIZE as usize];
+        let result = unsafe {
+            backend.nvmlDeviceGetName(dev, name.as_mut_ptr(), NVML_DEVICE_NAME_BUFFER_SIZE)
+        };
+        assert_eq!(result, nvmlReturn_enum_NVML_ERROR_UNINITIALIZED);
+    }
+}
@@ -0,0 +1,213 @@
+//! Why a device runs below its applications clocks.
+//!
+//! [`ThrottleReasons`] says what holds the clocks down right now; violation times
+//! from [`Handler::get_violation_status`] accumulate, so the difference between two
+//! readings tells how long a policy throttled the device in between.
+
+use std::os::raw::c_ulonglong;
+use std::time::Duration;
+
+use bitflags::bitflags;
+use nvml_binding::*;
+
+use crate::error::Result;
+use crate::{Device, Handler};
+
+bitflags! {
+    /// The `nvmlClocksThrottleReason*` bitmask.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct ThrottleReasons: u64 {
+        const GPU_IDLE = nvmlClocksThrottleReasonGpuIdle as u64;
+        const APPLICATIONS_CLOCKS_SETTING =
+            nvmlClocksThrottleReasonApplicationsClocksSetting as u64;
+        const SW_POWER_CAP = nvmlClocksThrottleReasonSwPowerCap as u64;
+        const HW_SLOWDOWN = nvmlClocksThrottleReasonHwSlowdown as u64;
+        const SYNC_BOOST = nvmlClocksThrottleReasonSyncBoost as u64;
+        const SW_THERMAL_SLOWDOWN = nvmlClocksThrottleReasonSwThermalSlowdown as u64;
+        const HW_THERMAL_SLOWDOWN = nvmlClocksThrottleReasonHwThermalSlowdown as u64;
+        const HW_POWER_BRAKE_SLOWDOWN = nvmlClocksThrottleReasonHwPowerBrakeSlowdown as u64;
+        const DISPLAY_CLOCK_SETTING = nvmlClocksThrottleReasonDisplayClockSetting as u64;
+    }
+}
+
+impl ThrottleReasons {
+    /// One sentence per reason set, in bit order. Unknown bits are skipped.
+    pub fn explanations(&self) -> Vec<&'static str> {
+        self.iter()
+            .filter_map(|reason| reason.explanation())
+            .collect()
+    }
+
+    fn explanation(self) -> Option<&'static str> {
+        Some(match self {
+            ThrottleReasons::GPU_IDLE => "Nothing is running on the GPU, so clocks are lowered.",
+            ThrottleReasons::APPLICATIONS_CLOCKS_SETTING => {
+                "Clocks are limited by the applications clocks setting."
+            }
+            ThrottleReasons::SW_POWER_CAP => {
+                "The power management algorithm lowered clocks to stay under the power limit."
+            }
+            ThrottleReasons::HW_SLOWDOWN => {
+                "Hardware slowdown is engaged, because the GPU is too hot, an external \
+                 power brake is asserted or the power supply is overloaded."
+            }
+            ThrottleReasons::SYNC_BOOST => {
+                "Clocks are held to those of other GPUs in the same sync boost group."
+            }
+            ThrottleReasons::SW_THERMAL_SLOWDOWN => {
+                "Clocks are lowered to keep the GPU or memory below their maximum operating \
+                 temperature."
+            }
+            ThrottleReasons::HW_THERMAL_SLOWDOWN => {
+                "Hardware thermal slowdown is engaged because the GPU is too hot."
+            }
+            ThrottleReasons::HW_POWER_BRAKE_SLOWDOWN => {
+                "An external power brake, such as from the system power supply, is asserted."
+            }
+            ThrottleReasons::DISPLAY_CLOCK_SETTING => {
+                "Clocks are limited by the display clock setting."
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// A policy that can hold clocks below what was requested, for
+/// [`Handler::get_violation_status`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PerfPolicy {
+    Power,
+    Thermal,
+    SyncBoost,
+    BoardLimit,
+    LowUtilization,
+    Reliability,
+    /// Any of the above, relative to applications clocks.
+    TotalApplicationsClocks,
+    /// Any of the above, relative to base clocks.
+    TotalBaseClocks,
+}
+
+impl From<PerfPolicy> for nvmlPerfPolicyType_t {
+    fn from(policy: PerfPolicy) -> nvmlPerfPolicyType_t {
+        match policy {
+            PerfPolicy::Power => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_POWER,
+            PerfPolicy::Thermal => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_THERMAL,
+            PerfPolicy::SyncBoost => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_SYNC_BOOST,
+            PerfPolicy::BoardLimit => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_BOARD_LIMIT,
+            PerfPolicy::LowUtilization => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_LOW_UTILIZATION,
+            PerfPolicy::Reliability => nvmlPerfPolicyType_enum_NVML_PERF_POLICY_RELIABILITY,
+            PerfPolicy::TotalApplicationsClocks => {
+                nvmlPerfPolicyType_enum_NVML_PERF_POLICY_TOTAL_APP_CLOCKS
+            }
+            PerfPolicy::TotalBaseClocks => {
+                nvmlPerfPolicyType_enum_NVML_PERF_POLICY_TOTAL_BASE_CLOCKS
+            }
+        }
+    }
+}
+
+/// Cumulative time a policy held the clocks down.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ViolationTime {
+    /// CPU timestamp in microseconds at which the reading was taken.
+    pub reference_time: u64,
+    pub violation_time: Duration,
+}
+
+impl Handler<'_> {
+    pub fn get_current_clocks_throttle_reasons(&self) -> Result<ThrottleReasons> {
+        unsafe {
+            let mut reasons: c_ulonglong = 0;
+            let result = self
+                .backend
+                .nvmlDeviceGetCurrentClocksThrottleReasons(self.dev, &mut reasons as *mut _);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetCurrentClocksThrottleReasons"));
+            }
+            Ok(ThrottleReasons::from_bits_retain(reasons))
+        }
+    }
+
+    pub fn get_supported_clocks_throttle_reasons(&self) -> Result<ThrottleReasons> {
+        unsafe {
+            let mut reasons: c_ulonglong = 0;
+            let result = self
+                .backend
+                .nvmlDeviceGetSupportedClocksThrottleReasons(self.dev, &mut reasons as *mut _);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetSupportedClocksThrottleReasons"));
+            }
+            Ok(ThrottleReasons::from_bits_retain(reasons))
+        }
+    }
+
+    pub fn get_violation_status(&self, policy: PerfPolicy) -> Result<ViolationTime> {
+        unsafe {
+            let mut time: nvmlViolationTime_t = std::mem::zeroed();
+            let result = self.backend.nvmlDeviceGetViolationStatus(
+                self.dev,
+                policy.into(),
+                &mut time as *mut nvmlViolationTime_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetViolationStatus"));
+            }
+            Ok(ViolationTime {
+                reference_time: time.referenceTime,
+                violation_time: Duration::from_nanos(time.violationTime),
+            })
+        }
+    }
+}
+
+impl Device<'_> {
+    /// The reasons currently holding the clocks down; empty if there are none.
+    pub fn throttle_reasons(&self) -> Result<ThrottleReasons> {
+        self.handler.get_current_clocks_throttle_reasons()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::SimulatedBackend;
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn throttle_reasons_are_explained() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(1));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        assert_eq!(
+            device.throttle_reasons().unwrap(),
+            ThrottleReasons::GPU_IDLE
+        );
+
+        backend.update(0, |d| {
+            d.throttle_reasons =
+                (ThrottleReasons::SW_POWER_CAP | ThrottleReasons::HW_SLOWDOWN).bits() | 1 << 40;
+            d.violation_times[nvmlPerfPolicyType_enum_NVML_PERF_POLICY_POWER as usize] =
+                1_500_000_000;
+        });
+        let reasons = device.throttle_reasons().unwrap();
+        assert!(reasons.contains(ThrottleReasons::SW_POWER_CAP));
+        let explanations = reasons.explanations();
+        assert_eq!(explanations.len(), 2);
+        assert!(explanations[0].contains("power limit"));
+        assert_eq!(
+            device
+                .handler
+                .get_supported_clocks_throttle_reasons()
+                .unwrap(),
+            ThrottleReasons::all()
+        );
+        let violation = device
+            .handler
+            .get_violation_status(PerfPolicy::Power)
+            .unwrap();
+        assert_eq!(violation.violation_time, Duration::from_millis(1500));
+        assert!(violation.reference_time > 0);
+    }
+}
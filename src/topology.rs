@@ -0,0 +1,552 @@
+//! How the GPUs of a system reach each other.
+//!
+//! [`Topology`] queries every pair of GPUs once, so it answers pairwise questions
+//! without further NVML calls, and renders like `nvidia-smi topo -m`:
+//!
+//! ```no_run
+//! let nvml = nvml_rs::NVML::new().unwrap();
+//! print!("{}", nvml.topology().unwrap());
+//! ```
+
+use std::fmt;
+use std::os::raw::c_uint;
+
+use nvml_binding::*;
+
+use crate::error::{not_supported_as_none, Error, Result};
+use crate::nvlink::NvLink;
+use crate::{query_list, Device, Handler, P2PLink, P2PLinkType, NVML};
+
+/// The closest common ancestor of two GPUs in the PCIe tree, from closest to
+/// farthest, so that levels compare by distance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TopologyLevel {
+    /// Both GPUs are on the same board, such as a Tesla K80.
+    Internal,
+    /// Connected through a single PCIe switch.
+    Single,
+    /// Connected through several PCIe switches, without a host bridge.
+    Multiple,
+    /// Connected through the same PCIe host bridge.
+    HostBridge,
+    /// Connected through host bridges of the same NUMA node.
+    Node,
+    /// Connected across NUMA nodes, such as over QPI or UPI.
+    System,
+}
+
+impl TopologyLevel {
+    pub const ALL: [TopologyLevel; 6] = [
+        TopologyLevel::Internal,
+        TopologyLevel::Single,
+        TopologyLevel::Multiple,
+        TopologyLevel::HostBridge,
+        TopologyLevel::Node,
+        TopologyLevel::System,
+    ];
+
+    fn from_raw(level: nvmlGpuTopologyLevel_t) -> Option<TopologyLevel> {
+        Some(match level {
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_INTERNAL => TopologyLevel::Internal,
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_SINGLE => TopologyLevel::Single,
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_MULTIPLE => TopologyLevel::Multiple,
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_HOSTBRIDGE => TopologyLevel::HostBridge,
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_NODE => TopologyLevel::Node,
+            nvmlGpuLevel_enum_NVML_TOPOLOGY_SYSTEM => TopologyLevel::System,
+            _ => return None,
+        })
+    }
+
+    /// The abbreviation `nvidia-smi topo -m` prints for the level.
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            TopologyLevel::Internal => "BRD",
+            TopologyLevel::Single => "PIX",
+            TopologyLevel::Multiple => "PXB",
+            TopologyLevel::HostBridge => "PHB",
+            TopologyLevel::Node => "NODE",
+            TopologyLevel::System => "SYS",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            TopologyLevel::Internal => "Connection between GPUs on the same board",
+            TopologyLevel::Single => "Connection traversing at most a single PCIe bridge",
+            TopologyLevel::Multiple => {
+                "Connection traversing multiple PCIe bridges (without traversing the PCIe \
+                 Host Bridge)"
+            }
+            TopologyLevel::HostBridge => {
+                "Connection traversing PCIe as well as a PCIe Host Bridge (typically the CPU)"
+            }
+            TopologyLevel::Node => {
+                "Connection traversing PCIe as well as the interconnect between PCIe Host \
+                 Bridges within a NUMA node"
+            }
+            TopologyLevel::System => {
+                "Connection traversing PCIe as well as the SMP interconnect between NUMA nodes \
+                 (e.g., QPI/UPI)"
+            }
+        }
+    }
+}
+
+impl From<TopologyLevel> for nvmlGpuTopologyLevel_t {
+    fn from(level: TopologyLevel) -> nvmlGpuTopologyLevel_t {
+        match level {
+            TopologyLevel::Internal => nvmlGpuLevel_enum_NVML_TOPOLOGY_INTERNAL,
+            TopologyLevel::Single => nvmlGpuLevel_enum_NVML_TOPOLOGY_SINGLE,
+            TopologyLevel::Multiple => nvmlGpuLevel_enum_NVML_TOPOLOGY_MULTIPLE,
+            TopologyLevel::HostBridge => nvmlGpuLevel_enum_NVML_TOPOLOGY_HOSTBRIDGE,
+            TopologyLevel::Node => nvmlGpuLevel_enum_NVML_TOPOLOGY_NODE,
+            TopologyLevel::System => nvmlGpuLevel_enum_NVML_TOPOLOGY_SYSTEM,
+        }
+    }
+}
+
+impl fmt::Display for TopologyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+/// A kind of peer-to-peer access, for [`Handler::get_p2p_status`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum P2PCapability {
+    Read,
+    Write,
+    NvLink,
+    Atomics,
+    Prop,
+}
+
+impl P2PCapability {
+    pub const ALL: [P2PCapability; 5] = [
+        P2PCapability::Read,
+        P2PCapability::Write,
+        P2PCapability::NvLink,
+        P2PCapability::Atomics,
+        P2PCapability::Prop,
+    ];
+}
+
+impl From<P2PCapability> for nvmlGpuP2PCapsIndex_t {
+    fn from(capability: P2PCapability) -> nvmlGpuP2PCapsIndex_t {
+        match capability {
+            P2PCapability::Read => nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_READ,
+            P2PCapability::Write => nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_WRITE,
+            P2PCapability::NvLink => nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_NVLINK,
+            P2PCapability::Atomics => nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_ATOMICS,
+            P2PCapability::Prop => nvmlGpuP2PCapsIndex_enum_NVML_P2P_CAPS_INDEX_PROP,
+        }
+    }
+}
+
+/// Whether a [`P2PCapability`] is available between two GPUs, and if not, why.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum P2PStatus {
+    Ok,
+    ChipsetNotSupported,
+    GpuNotSupported,
+    /// The PCIe topology between the GPUs does not allow it, typically because
+    /// they are on different host bridges.
+    IohTopologyNotSupported,
+    DisabledByRegkey,
+    NotSupported,
+    Unknown,
+}
+
+impl P2PStatus {
+    pub fn is_ok(self) -> bool {
+        self == P2PStatus::Ok
+    }
+}
+
+impl From<nvmlGpuP2PStatus_t> for P2PStatus {
+    fn from(status: nvmlGpuP2PStatus_t) -> P2PStatus {
+        match status {
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_OK => P2PStatus::Ok,
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_CHIPSET_NOT_SUPPORED => {
+                P2PStatus::ChipsetNotSupported
+            }
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_GPU_NOT_SUPPORTED => P2PStatus::GpuNotSupported,
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_IOH_TOPOLOGY_NOT_SUPPORTED => {
+                P2PStatus::IohTopologyNotSupported
+            }
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_DISABLED_BY_REGKEY => P2PStatus::DisabledByRegkey,
+            nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_NOT_SUPPORTED => P2PStatus::NotSupported,
+            _ => P2PStatus::Unknown,
+        }
+    }
+}
+
+/// How one GPU reaches another.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GpuConnection {
+    pub level: TopologyLevel,
+    /// Active NvLinks between the two GPUs.
+    pub nvlinks: u32,
+    pub read: P2PStatus,
+    pub write: P2PStatus,
+    pub atomics: P2PStatus,
+    pub nvlink: P2PStatus,
+}
+
+impl GpuConnection {
+    /// The link type [`Device::p2p_links`] reports; NvLinks take precedence over
+    /// the PCIe path.
+    pub fn link_type(&self) -> P2PLinkType {
+        match self.nvlinks {
+            0 => match self.level {
+                TopologyLevel::Internal => P2PLinkType::P2PLinkSameBoard,
+                TopologyLevel::Single => P2PLinkType::P2PLinkSingleSwitch,
+                TopologyLevel::Multiple => P2PLinkType::P2PLinkMultiSwitch,
+                TopologyLevel::HostBridge => P2PLinkType::P2PLinkHostBridge,
+                TopologyLevel::Node => P2PLinkType::P2PLinkSameCPU,
+                TopologyLevel::System => P2PLinkType::P2PLinkCrossCPU,
+            },
+            1 => P2PLinkType::SingleNVLINKLink,
+            2 => P2PLinkType::TwoNVLINKLinks,
+            3 => P2PLinkType::ThreeNVLINKLinks,
+            4 => P2PLinkType::FourNVLINKLinks,
+            5 => P2PLinkType::FiveNVLINKLinks,
+            _ => P2PLinkType::SixNVLINKLinks,
+        }
+    }
+}
+
+/// The connections between every pair of GPUs, indexed by device index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Topology {
+    bus_ids: Vec<String>,
+    // Row-major, `None` on the diagonal and for pairs NVML cannot tell about.
+    connections: Vec<Option<GpuConnection>>,
+}
+
+impl Topology {
+    pub fn new(nvml: &NVML) -> Result<Topology> {
+        let count = nvml.device_count()?;
+        let handlers = (0..count)
+            .map(|index| Handler::new(nvml, index))
+            .collect::<Result<Vec<_>>>()?;
+        let mut bus_ids = Vec::with_capacity(handlers.len());
+        let mut connections = Vec::with_capacity(handlers.len() * handlers.len());
+        for handler in handlers.iter() {
+            bus_ids.push(handler.get_pci_info()?);
+            let nvlinks = handler.get_nvlinks()?;
+            for other in handlers.iter() {
+                connections.push(if other.index() == handler.index() {
+                    None
+                } else {
+                    handler.get_connection(other, &nvlinks)?
+                });
+            }
+        }
+        Ok(Topology {
+            bus_ids,
+            connections,
+        })
+    }
+
+    pub fn device_count(&self) -> u32 {
+        self.bus_ids.len() as u32
+    }
+
+    /// The PCI bus id of the device at `index`.
+    pub fn bus_id(&self, index: u32) -> Option<&str> {
+        self.bus_ids.get(index as usize).map(String::as_str)
+    }
+
+    /// How device `from` reaches device `to`; `None` for the same device, unknown
+    /// indices and pairs NVML cannot tell about.
+    pub fn connection(&self, from: u32, to: u32) -> Option<&GpuConnection> {
+        let count = self.bus_ids.len();
+        if from as usize >= count || to as usize >= count {
+            return None;
+        }
+        self.connections[from as usize * count + to as usize].as_ref()
+    }
+
+    /// The devices that share an ancestor with `index` at `level` or closer,
+    /// closest first.
+    pub fn nearest(&self, index: u32, level: TopologyLevel) -> Vec<u32> {
+        let mut nearest: Vec<(TopologyLevel, u32)> = (0..self.device_count())
+            .filter_map(|other| {
+                self.connection(index, other)
+                    .map(|connection| (connection.level, other))
+            })
+            .filter(|(l, _)| *l <= level)
+            .collect();
+        nearest.sort();
+        nearest.into_iter().map(|(_, other)| other).collect()
+    }
+
+    // The cell of the `nvidia-smi topo -m` matrix.
+    fn cell(&self, from: u32, to: u32) -> String {
+        if from == to {
+            return "X".to_owned();
+        }
+        match self.connection(from, to) {
+            Some(connection) if connection.nvlinks > 0 => format!("NV{}", connection.nvlinks),
+            Some(connection) => connection.level.abbreviation().to_owned(),
+            None => "N/A".to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Topology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for to in 0..self.device_count() {
+            write!(f, "\tGPU{}", to)?;
+        }
+        writeln!(f)?;
+        for from in 0..self.device_count() {
+            write!(f, "GPU{}", from)?;
+            for to in 0..self.device_count() {
+                write!(f, "\t{}", self.cell(from, to))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Legend:")?;
+        writeln!(f)?;
+        writeln!(f, "  {:<4} = Self", "X")?;
+        for level in TopologyLevel::ALL.iter().rev() {
+            writeln!(f, "  {:<4} = {}", level.abbreviation(), level.description())?;
+        }
+        writeln!(
+            f,
+            "  {:<4} = Connection traversing a bonded set of # NVLinks",
+            "NV#"
+        )
+    }
+}
+
+impl NVML {
+    pub fn topology(&self) -> Result<Topology> {
+        Topology::new(self)
+    }
+
+    /// The indices of the GPUs with an affinity to CPU `cpu`.
+    pub fn topology_gpu_set(&self, cpu: u32) -> Result<Vec<u32>> {
+        let devices = unsafe {
+            query_list(|count, devices| {
+                self.backend
+                    .nvmlSystemGetTopologyGpuSet(cpu as c_uint, count, devices)
+            })
+        }
+        .map_err(|result| Error::nvml(result, "nvmlSystemGetTopologyGpuSet"))?;
+        devices
+            .into_iter()
+            .map(|dev| device_index(&*self.backend, dev))
+            .collect()
+    }
+}
+
+impl Handler<'_> {
+    pub fn get_topology_common_ancestor(&self, other: &Handler) -> Result<TopologyLevel> {
+        unsafe {
+            let mut level: nvmlGpuTopologyLevel_t = nvmlGpuLevel_enum_NVML_TOPOLOGY_SYSTEM;
+            let result = self.backend.nvmlDeviceGetTopologyCommonAncestor(
+                self.dev,
+                other.dev,
+                &mut level as *mut nvmlGpuTopologyLevel_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetTopologyCommonAncestor"));
+            }
+            TopologyLevel::from_raw(level).ok_or_else(|| {
+                self.error(
+                    nvmlReturn_enum_NVML_ERROR_UNKNOWN,
+                    "nvmlDeviceGetTopologyCommonAncestor",
+                )
+            })
+        }
+    }
+
+    /// The indices of the GPUs that share an ancestor with this one at `level` or
+    /// closer.
+    pub fn get_topology_nearest_gpus(&self, level: TopologyLevel) -> Result<Vec<u32>> {
+        let devices = unsafe {
+            query_list(|count, devices| {
+                self.backend.nvmlDeviceGetTopologyNearestGpus(
+                    self.dev,
+                    level.into(),
+                    count,
+                    devices,
+                )
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetTopologyNearestGpus"))?;
+        devices
+            .into_iter()
+            .map(|dev| device_index(&*self.backend, dev))
+            .collect()
+    }
+
+    pub fn get_p2p_status(&self, other: &Handler, capability: P2PCapability) -> Result<P2PStatus> {
+        unsafe {
+            let mut status: nvmlGpuP2PStatus_t = nvmlGpuP2PStatus_enum_NVML_P2P_STATUS_UNKNOWN;
+            let result = self.backend.nvmlDeviceGetP2PStatus(
+                self.dev,
+                other.dev,
+                capability.into(),
+                &mut status as *mut nvmlGpuP2PStatus_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetP2PStatus"));
+            }
+            Ok(status.into())
+        }
+    }
+
+    /// How the device reaches every other GPU, leaving out those NVML cannot place
+    /// relative to it. Every other GPU is queried, so prefer [`Topology`] to cover
+    /// all pairs.
+    pub fn get_p2p_links(&self) -> Result<Vec<P2PLink>> {
+        let mut count: c_uint = 0;
+        let result = unsafe {
+            self.backend
+                .nvmlDeviceGetCount_v2(&mut count as *mut c_uint)
+        };
+        if result != nvmlReturn_enum_NVML_SUCCESS {
+            return Err(Error::nvml(result, "nvmlDeviceGetCount_v2"));
+        }
+        let nvlinks = self.get_nvlinks()?;
+        let mut links = vec![];
+        for index in (0..count).filter(|index| *index != self.index()) {
+            let other = Handler::with_backend(&self.backend, index)?;
+            if let Some(connection) = self.get_connection(&other, &nvlinks)? {
+                links.push(P2PLink {
+                    bus_id: other.get_pci_info()?,
+                    link: connection.link_type(),
+                });
+            }
+        }
+        Ok(links)
+    }
+
+    // How this device reaches `other`, given its own NvLinks; `None` if NVML
+    // cannot tell the common ancestor.
+    fn get_connection(&self, other: &Handler, nvlinks: &[NvLink]) -> Result<Option<GpuConnection>> {
+        let level = match not_supported_as_none(self.get_topology_common_ancestor(other))? {
+            Some(level) => level,
+            None => return Ok(None),
+        };
+        let p2p_status = |capability| -> Result<P2PStatus> {
+            Ok(
+                not_supported_as_none(self.get_p2p_status(other, capability))?
+                    .unwrap_or(P2PStatus::NotSupported),
+            )
+        };
+        Ok(Some(GpuConnection {
+            level,
+            nvlinks: nvlinks
+                .iter()
+                .filter(|link| {
+                    link.active
+                        && link.remote.as_ref().and_then(|remote| remote.device_index)
+                            == Some(other.index())
+                })
+                .count() as u32,
+            read: p2p_status(P2PCapability::Read)?,
+            write: p2p_status(P2PCapability::Write)?,
+            atomics: p2p_status(P2PCapability::Atomics)?,
+            nvlink: p2p_status(P2PCapability::NvLink)?,
+        }))
+    }
+}
+
+impl Device<'_> {
+    /// How the device reaches every other GPU; see [`Handler::get_p2p_links`].
+    /// The first successful call fills [`Device::topology`], later calls return it.
+    pub fn p2p_links(&self) -> Result<&[P2PLink]> {
+        if let Some(links) = self.topology.get() {
+            return Ok(links);
+        }
+        let links = self.handler.get_p2p_links()?;
+        Ok(self.topology.get_or_init(|| links))
+    }
+}
+
+fn device_index(backend: &dyn Backend, dev: nvmlDevice_t) -> Result<u32> {
+    unsafe {
+        let mut index: c_uint = 0;
+        let result = backend.nvmlDeviceGetIndex(dev, &mut index as *mut c_uint);
+        if result != nvmlReturn_enum_NVML_SUCCESS {
+            return Err(Error::nvml(result, "nvmlDeviceGetIndex"));
+        }
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice, SimulatedNvLink};
+    use std::sync::Arc;
+
+    #[test]
+    fn topology_combines_pcie_levels_and_nvlinks() {
+        use crate::{P2PLink, P2PLinkType};
+
+        let mut devices: Vec<SimulatedDevice> = (0..3).map(SimulatedDevice::new).collect();
+        let bus_id_0 = devices[0].pci_bus_id();
+        let bus_id_1 = devices[1].pci_bus_id();
+        devices[0].nvlinks = vec![SimulatedNvLink::new(&bus_id_1); 2];
+        devices[1].nvlinks = vec![SimulatedNvLink::new(&bus_id_0); 2];
+        devices[0].topology = vec![(1, nvmlGpuLevel_enum_NVML_TOPOLOGY_NODE)];
+        devices[2].topology = vec![(1, nvmlGpuLevel_enum_NVML_TOPOLOGY_SINGLE)];
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(devices))).unwrap();
+
+        let topology = nvml.topology().unwrap();
+        let connection = topology.connection(1, 0).unwrap();
+        assert_eq!(connection.level, TopologyLevel::Node);
+        assert_eq!(connection.nvlinks, 2);
+        assert_eq!(connection.nvlink, P2PStatus::Ok);
+        assert!(connection.read.is_ok());
+        let connection = topology.connection(0, 2).unwrap();
+        assert_eq!(connection.level, TopologyLevel::System);
+        assert_eq!(connection.write, P2PStatus::IohTopologyNotSupported);
+        assert!(topology.connection(0, 0).is_none());
+        assert_eq!(topology.nearest(1, TopologyLevel::Node), vec![2, 0]);
+        assert_eq!(topology.nearest(1, TopologyLevel::Single), vec![2]);
+        let handler = Handler::new(&nvml, 1).unwrap();
+        assert_eq!(
+            handler
+                .get_topology_nearest_gpus(TopologyLevel::Single)
+                .unwrap(),
+            vec![2]
+        );
+
+        let matrix = topology.to_string();
+        let rows: Vec<&str> = matrix.lines().take(4).collect();
+        assert_eq!(
+            rows,
+            [
+                "\tGPU0\tGPU1\tGPU2",
+                "GPU0\tX\tNV2\tSYS",
+                "GPU1\tNV2\tX\tPIX",
+                "GPU2\tSYS\tPIX\tX",
+            ]
+        );
+
+        let device = Device::new(&nvml, 2).unwrap();
+        assert!(device.topology.get().is_none());
+        assert_eq!(
+            device.p2p_links().unwrap(),
+            [
+                P2PLink {
+                    bus_id: bus_id_0,
+                    link: P2PLinkType::P2PLinkCrossCPU,
+                },
+                P2PLink {
+                    bus_id: bus_id_1,
+                    link: P2PLinkType::P2PLinkSingleSwitch,
+                },
+            ]
+        );
+        assert_eq!(device.topology.get().unwrap().len(), 2);
+    }
+}
@@ -1,94 +1,104 @@
 use crate::error::{Error, Result};
+use crate::{query_list, NVML};
 
-use ::std::os::raw::{c_char, c_int, c_uint};
+use ::std::marker::PhantomData;
+use ::std::os::raw::c_uint;
+use ::std::sync::Arc;
 use nvml_binding::*;
 
-pub struct Unit {
+pub struct Unit<'nvml> {
     pub handle: nvmlUnit_t,
+    backend: Arc<dyn Backend>,
+    nvml: PhantomData<&'nvml NVML>,
 }
 
-impl Unit {
-    pub fn new(index: u32) -> Result<Unit> {
+impl<'nvml> Unit<'nvml> {
+    pub fn new(nvml: &'nvml NVML, index: u32) -> Result<Self> {
         unsafe {
-            let mut handle: nvmlUnit_t = std::mem::uninitialized();
-            let result = nvmlUnitGetHandleByIndex(index as c_uint, &mut handle as *mut nvmlUnit_t);
-            if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Ok(Unit { handle });
+            let mut handle: nvmlUnit_t = std::ptr::null_mut();
+            let result = nvml
+                .backend
+                .nvmlUnitGetHandleByIndex(index as c_uint, &mut handle as *mut nvmlUnit_t);
+            if result == nvmlReturn_enum_NVML_SUCCESS {
+                return Ok(Unit {
+                    handle,
+                    backend: nvml.backend.clone(),
+                    nvml: PhantomData,
+                });
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetHandleByIndex"))
         }
     }
 
     pub fn info(&self) -> Result<nvmlUnitInfo_t> {
         unsafe {
-            let mut info = std::mem::uninitialized();
-            let result = nvmlUnitGetUnitInfo(self.handle, &mut info as *mut nvmlUnitInfo_t);
+            let mut info = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlUnitGetUnitInfo(self.handle, &mut info as *mut nvmlUnitInfo_t);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(info);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetUnitInfo"))
         }
     }
     pub fn led_state(&self) -> Result<nvmlLedState_t> {
         unsafe {
-            let mut state = std::mem::uninitialized();
-            let result = nvmlUnitGetLedState(self.handle, &mut state as *mut nvmlLedState_t);
+            let mut state = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlUnitGetLedState(self.handle, &mut state as *mut nvmlLedState_t);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(state);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetLedState"))
         }
     }
     pub fn psu_info(&self) -> Result<nvmlPSUInfo_t> {
         unsafe {
-            let mut psu = std::mem::uninitialized();
-            let result = nvmlUnitGetPsuInfo(self.handle, &mut psu as *mut nvmlPSUInfo_t);
+            let mut psu = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlUnitGetPsuInfo(self.handle, &mut psu as *mut nvmlPSUInfo_t);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(psu);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetPsuInfo"))
         }
     }
     pub fn temperature(&self, tt: TemperatureType) -> Result<u64> {
         unsafe {
             let mut temperature: c_uint = 0;
-            let result =
-                nvmlUnitGetTemperature(self.handle, tt as c_uint, &mut temperature as *mut c_uint);
+            let result = self.backend.nvmlUnitGetTemperature(
+                self.handle,
+                tt as c_uint,
+                &mut temperature as *mut c_uint,
+            );
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(temperature as u64);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetTemperature"))
         }
     }
     pub fn fan_speed(&self) -> Result<nvmlUnitFanSpeeds_t> {
         unsafe {
-            let mut fan_speed: nvmlUnitFanSpeeds_t = std::mem::uninitialized();
-            let result =
-                nvmlUnitGetFanSpeedInfo(self.handle, &mut fan_speed as *mut nvmlUnitFanSpeeds_t);
+            let mut fan_speed: nvmlUnitFanSpeeds_t = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlUnitGetFanSpeedInfo(self.handle, &mut fan_speed as *mut nvmlUnitFanSpeeds_t);
             if result == nvmlReturn_enum_NVML_SUCCESS {
                 return Ok(fan_speed);
             }
-            Err(result.into())
+            Err(Error::nvml(result, "nvmlUnitGetFanSpeedInfo"))
         }
     }
     pub fn devices(&self) -> Result<Vec<nvmlDevice_t>> {
         unsafe {
-            let mut devices: nvmlDevice_t = std::ptr::null_mut();
-            let mut count: c_uint = 0;
-            let result = nvmlUnitGetDevices(
-                self.handle,
-                &mut count as *mut c_uint,
-                &mut devices as *mut nvmlDevice_t,
-            );
-            if result != nvmlReturn_enum_NVML_SUCCESS {
-                return Err(result.into());
-            }
-            Ok(Vec::from_raw_parts(
-                &mut devices as *mut nvmlDevice_t,
-                count as usize,
-                count as usize,
-            ))
+            query_list(|count, devices| {
+                self.backend.nvmlUnitGetDevices(self.handle, count, devices)
+            })
         }
+        .map_err(|result| Error::nvml(result, "nvmlUnitGetDevices"))
     }
 }
 
@@ -107,3 +117,37 @@ impl From<TemperatureType> for u64 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedUnit};
+    use crate::Handler;
+
+    #[test]
+    fn devices_are_listed_per_unit() {
+        let mut backend = SimulatedBackend::with_gpus(3);
+        backend.units = vec![
+            SimulatedUnit {
+                devices: vec![0, 2],
+            },
+            SimulatedUnit::default(),
+        ];
+        let nvml = NVML::with_backend(Arc::new(backend)).unwrap();
+        assert_eq!(nvml.unit_count().unwrap(), 2);
+
+        let devices = nvml.unit_handle_by_index(0).unwrap().devices().unwrap();
+        let expected: Vec<nvmlDevice_t> = [0, 2]
+            .iter()
+            .map(|index| Handler::new(&nvml, *index).unwrap().dev)
+            .collect();
+        assert_eq!(devices, expected);
+        assert!(nvml
+            .unit_handle_by_index(1)
+            .unwrap()
+            .devices()
+            .unwrap()
+            .is_empty());
+        assert!(nvml.unit_handle_by_index(2).is_err());
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::BTreeMap;
+use std::os::raw::{c_uint, c_ulonglong};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use nvml_binding::*;
+
+use crate::error::{not_supported_as_none, Result};
+use crate::{query_list, Device, Handler};
+
+/// Utilization of the video encoder or decoder.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CodecUtilization {
+    /// Percent of the sampling period the engine was busy.
+    pub utilization: u32,
+    /// The period NVML sampled over.
+    pub sampling_period: Duration,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Utilization {
+    /// Percent of time over the past sample period during which one or more
+    /// kernels was executing on the GPU.
+    pub gpu: u32,
+    /// Percent of time over the past sample period during which device memory was
+    /// being read or written.
+    pub memory: u32,
+    /// `None` on devices without an encoder.
+    pub encoder: Option<CodecUtilization>,
+    /// `None` on devices without a decoder.
+    pub decoder: Option<CodecUtilization>,
+}
+
+/// Utilization of the device by one process over one sample period, in percent.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ProcessUtilizationSample {
+    pub pid: u32,
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub sm: u32,
+    pub memory: u32,
+    pub encoder: u32,
+    pub decoder: u32,
+}
+
+/// The average utilization of one process over a window of samples, in percent.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ProcessUtilization {
+    pub pid: u32,
+    /// How many samples were averaged.
+    pub samples: usize,
+    pub sm: f64,
+    pub memory: f64,
+    pub encoder: f64,
+    pub decoder: f64,
+}
+
+impl ProcessUtilization {
+    /// Averages `samples` per process, ordered by pid.
+    pub fn average(samples: &[ProcessUtilizationSample]) -> Vec<ProcessUtilization> {
+        let mut by_pid: BTreeMap<u32, Vec<&ProcessUtilizationSample>> = BTreeMap::new();
+        for sample in samples.iter() {
+            by_pid.entry(sample.pid).or_default().push(sample);
+        }
+        by_pid
+            .into_iter()
+            .map(|(pid, samples)| {
+                let mean = |value: fn(&ProcessUtilizationSample) -> u32| {
+                    samples.iter().map(|s| value(s) as f64).sum::<f64>() / samples.len() as f64
+                };
+                ProcessUtilization {
+                    pid,
+                    samples: samples.len(),
+                    sm: mean(|s| s.sm),
+                    memory: mean(|s| s.memory),
+                    encoder: mean(|s| s.encoder),
+                    decoder: mean(|s| s.decoder),
+                }
+            })
+            .collect()
+    }
+}
+
+type CodecUtilizationFn =
+    unsafe fn(&(dyn Backend + 'static), nvmlDevice_t, *mut c_uint, *mut c_uint) -> nvmlReturn_t;
+
+impl Handler<'_> {
+    pub fn get_utilization_rates(&self) -> Result<nvmlUtilization_t> {
+        unsafe {
+            let mut utilization: nvmlUtilization_t = std::mem::zeroed();
+            let result = self
+                .backend
+                .nvmlDeviceGetUtilizationRates(self.dev, &mut utilization as *mut _);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlDeviceGetUtilizationRates"));
+            }
+            Ok(utilization)
+        }
+    }
+
+    pub fn get_encoder_utilization(&self) -> Result<CodecUtilization> {
+        self.get_codec_utilization(
+            <dyn Backend>::nvmlDeviceGetEncoderUtilization,
+            "nvmlDeviceGetEncoderUtilization",
+        )
+    }
+
+    pub fn get_decoder_utilization(&self) -> Result<CodecUtilization> {
+        self.get_codec_utilization(
+            <dyn Backend>::nvmlDeviceGetDecoderUtilization,
+            "nvmlDeviceGetDecoderUtilization",
+        )
+    }
+
+    /// Returns the per-process samples newer than `last_seen_timestamp`, oldest
+    /// first. Pass 0 to get the whole buffer.
+    ///
+    /// An empty buffer is not an error; NVML's `NOT_FOUND` yields no samples.
+    pub fn get_process_utilization(
+        &self,
+        last_seen_timestamp: u64,
+    ) -> Result<Vec<ProcessUtilizationSample>> {
+        let samples = unsafe {
+            query_list(|count, samples| {
+                self.backend.nvmlDeviceGetProcessUtilization(
+                    self.dev,
+                    samples,
+                    count,
+                    last_seen_timestamp as c_ulonglong,
+                )
+            })
+        };
+        let samples: Vec<nvmlProcessUtilizationSample_t> = match samples {
+            Ok(samples) => samples,
+            Err(nvmlReturn_enum_NVML_ERROR_NOT_FOUND) => return Ok(vec![]),
+            Err(result) => return Err(self.error(result, "nvmlDeviceGetProcessUtilization")),
+        };
+        let mut samples: Vec<ProcessUtilizationSample> = samples
+            .iter()
+            .map(|sample| ProcessUtilizationSample {
+                pid: sample.pid,
+                timestamp: sample.timeStamp,
+                sm: sample.smUtil,
+                memory: sample.memUtil,
+                encoder: sample.encUtil,
+                decoder: sample.decUtil,
+            })
+            .collect();
+        samples.sort_by_key(|sample| sample.timestamp);
+        Ok(samples)
+    }
+
+    fn get_codec_utilization(
+        &self,
+        f: CodecUtilizationFn,
+        function: &'static str,
+    ) -> Result<CodecUtilization> {
+        unsafe {
+            let mut utilization: c_uint = 0;
+            let mut sampling_period_us: c_uint = 0;
+            let result = f(
+                &*self.backend,
+                self.dev,
+                &mut utilization as *mut c_uint,
+                &mut sampling_period_us as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, function));
+            }
+            Ok(CodecUtilization {
+                utilization,
+                sampling_period: Duration::from_micros(sampling_period_us as u64),
+            })
+        }
+    }
+}
+
+impl Device<'_> {
+    pub fn utilization(&self) -> Result<Utilization> {
+        let rates = self.handler.get_utilization_rates()?;
+        Ok(Utilization {
+            gpu: rates.gpu,
+            memory: rates.memory,
+            encoder: not_supported_as_none(self.handler.get_encoder_utilization())?,
+            decoder: not_supported_as_none(self.handler.get_decoder_utilization())?,
+        })
+    }
+}
+
+/// Polls the per-process utilization of one device, returning each sample once.
+pub struct ProcessUtilizationCursor<'nvml> {
+    handler: Handler<'nvml>,
+    last_seen: u64,
+}
+
+impl<'nvml> ProcessUtilizationCursor<'nvml> {
+    pub fn new(handler: Handler<'nvml>) -> Self {
+        ProcessUtilizationCursor {
+            handler,
+            last_seen: 0,
+        }
+    }
+
+    /// Returns the samples recorded since the previous poll, or the whole buffer on
+    /// the first poll.
+    pub fn poll(&mut self) -> Result<Vec<ProcessUtilizationSample>> {
+        let samples = self.handler.get_process_utilization(self.last_seen)?;
+        if let Some(newest) = samples.last() {
+            self.last_seen = newest.timestamp;
+        }
+        Ok(samples)
+    }
+
+    /// Like [`ProcessUtilizationCursor::poll`], averaged per process.
+    pub fn poll_average(&mut self) -> Result<Vec<ProcessUtilization>> {
+        Ok(ProcessUtilization::average(&self.poll()?))
+    }
+
+    /// The newest timestamp returned, in microseconds; 0 before the first sample.
+    pub fn last_seen(&self) -> u64 {
+        self.last_seen
+    }
+
+    /// Forgets the cursor so the next poll returns the whole buffer again.
+    pub fn reset(&mut self) {
+        self.last_seen = 0;
+    }
+}
+
+impl<'nvml> Device<'nvml> {
+    pub fn process_utilization_cursor(&self) -> ProcessUtilizationCursor<'nvml> {
+        ProcessUtilizationCursor::new(self.handler.clone())
+    }
+
+    /// The average utilization of every process over the last `window`, as far as
+    /// the sample buffer of the driver reaches back.
+    pub fn process_utilization(&self, window: Duration) -> Result<Vec<ProcessUtilization>> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let since = now.checked_sub(window).unwrap_or_default().as_micros() as u64;
+        let samples = self.handler.get_process_utilization(since)?;
+        Ok(ProcessUtilization::average(&samples))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulated::{SimulatedBackend, SimulatedDevice, SimulatedProcessUtilization};
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn utilization_reports_missing_codecs_as_none() {
+        let backend = Arc::new(SimulatedBackend::new(vec![SimulatedDevice {
+            gpu_utilization: 87,
+            memory_utilization: 41,
+            encoder_utilization: Some(12),
+            decoder_utilization: None,
+            ..SimulatedDevice::new(0)
+        }]));
+        let nvml = NVML::with_backend(backend).unwrap();
+        let utilization = Device::new(&nvml, 0).unwrap().utilization().unwrap();
+        assert_eq!(utilization.gpu, 87);
+        assert_eq!(utilization.memory, 41);
+        let encoder = utilization.encoder.unwrap();
+        assert_eq!(encoder.utilization, 12);
+        assert_eq!(encoder.sampling_period, Duration::from_millis(167));
+        assert_eq!(utilization.decoder, None);
+    }
+
+    #[test]
+    fn process_utilization_cursor_returns_new_samples_once() {
+        let backend = Arc::new(SimulatedBackend::with_gpus(1));
+        let nvml = NVML::with_backend(backend.clone()).unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+        let sample = |pid, timestamp, sm| SimulatedProcessUtilization {
+            pid,
+            timestamp,
+            sm,
+            memory: sm / 2,
+            ..Default::default()
+        };
+        backend.update(0, |d| {
+            d.process_utilization = vec![
+                sample(10, 300, 40),
+                sample(20, 100, 90),
+                sample(10, 200, 20),
+            ]
+        });
+
+        let mut cursor = device.process_utilization_cursor();
+        let samples = cursor.poll().unwrap();
+        let timestamps: Vec<u64> = samples.iter().map(|s| s.timestamp).collect();
+        assert_eq!(timestamps, vec![100, 200, 300]);
+        assert_eq!(cursor.last_seen(), 300);
+        assert!(cursor.poll().unwrap().is_empty());
+
+        backend.update(0, |d| d.process_utilization.push(sample(20, 400, 70)));
+        let averages = ProcessUtilization::average(&samples);
+        assert_eq!(averages.len(), 2);
+        assert_eq!(averages[0].pid, 10);
+        assert_eq!(averages[0].samples, 2);
+        assert_eq!(averages[0].sm, 30.0);
+        assert_eq!(averages[0].memory, 15.0);
+        let averages = cursor.poll_average().unwrap();
+        assert_eq!(averages.len(), 1);
+        assert_eq!((averages[0].pid, averages[0].sm), (20, 70.0));
+
+        cursor.reset();
+        assert_eq!(cursor.poll().unwrap().len(), 4);
+        // Every simulated sample is far older than the window.
+        assert!(device
+            .process_utilization(Duration::from_secs(1))
+            .unwrap()
+            .is_empty());
+    }
+}
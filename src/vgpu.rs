@@ -0,0 +1,847 @@
+//! vGPU support on hosts running the NVIDIA vGPU manager.
+//!
+//! A physical GPU supports a set of vGPU types, of which the creatable ones can be
+//! instantiated given what already runs on the GPU. Each running vGPU is a
+//! [`VgpuInstance`] attached to a guest VM. On hosts without the vGPU manager the
+//! lists are empty or the queries are not supported.
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_uint, c_ulonglong};
+use std::time::Duration;
+
+use nvml_binding::*;
+
+use crate::accounting::AccountingStats;
+use crate::encoder::{EncoderSession, EncoderStats, FbcSession, FbcStats};
+use crate::error::{not_supported_as_none, Result};
+use crate::sample::{query_samples, SampleValue};
+use crate::{query_list, Device, Handler};
+
+/// A vGPU type the device supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VgpuType {
+    pub id: u32,
+    /// Such as `Quadro` or `NVS`.
+    pub class: String,
+    /// Such as `GRID M60-2Q`.
+    pub name: String,
+    /// The PCI device id the vGPU shows to the guest.
+    pub device_id: u64,
+    pub subsystem_id: u64,
+    /// Framebuffer size in bytes.
+    pub framebuffer_size: u64,
+    /// The maximum resolution of each display head.
+    pub resolutions: Vec<(u32, u32)>,
+    /// The license the type requires, such as `GRID-Virtual-PC,2.0`.
+    pub license: String,
+    /// Frame rate limit in frames per second; `None` if the limiter is disabled.
+    pub frame_rate_limit: Option<u32>,
+    /// How many instances of the type fit on the device when it is empty.
+    pub max_instances: u32,
+    pub max_instances_per_vm: Option<u32>,
+    /// Whether an instance can be created now, given what runs on the device.
+    pub creatable: bool,
+}
+
+type VgpuTypeStringFn =
+    unsafe fn(&(dyn Backend + 'static), nvmlVgpuTypeId_t, *mut c_char, *mut c_uint) -> nvmlReturn_t;
+
+type VgpuTypeIdsFn = unsafe fn(
+    &(dyn Backend + 'static),
+    nvmlDevice_t,
+    *mut c_uint,
+    *mut nvmlVgpuTypeId_t,
+) -> nvmlReturn_t;
+
+impl Handler<'_> {
+    /// The ids of the vGPU types the device supports.
+    pub fn get_supported_vgpus(&self) -> Result<Vec<u32>> {
+        self.get_vgpu_type_ids(
+            <dyn Backend>::nvmlDeviceGetSupportedVgpus,
+            "nvmlDeviceGetSupportedVgpus",
+        )
+    }
+
+    /// The ids of the vGPU types an instance can be created of right now.
+    pub fn get_creatable_vgpus(&self) -> Result<Vec<u32>> {
+        self.get_vgpu_type_ids(
+            <dyn Backend>::nvmlDeviceGetCreatableVgpus,
+            "nvmlDeviceGetCreatableVgpus",
+        )
+    }
+
+    fn get_vgpu_type_ids(&self, f: VgpuTypeIdsFn, function: &'static str) -> Result<Vec<u32>> {
+        unsafe {
+            query_list(|count, ids| f(&*self.backend, self.dev, count, ids))
+                .map_err(|result| self.error(result, function))
+        }
+    }
+
+    pub fn get_vgpu_type_class(&self, vgpu_type: u32) -> Result<String> {
+        self.get_vgpu_type_string(
+            vgpu_type,
+            <dyn Backend>::nvmlVgpuTypeGetClass,
+            "nvmlVgpuTypeGetClass",
+        )
+    }
+
+    pub fn get_vgpu_type_name(&self, vgpu_type: u32) -> Result<String> {
+        self.get_vgpu_type_string(
+            vgpu_type,
+            <dyn Backend>::nvmlVgpuTypeGetName,
+            "nvmlVgpuTypeGetName",
+        )
+    }
+
+    fn get_vgpu_type_string(
+        &self,
+        vgpu_type: u32,
+        f: VgpuTypeStringFn,
+        function: &'static str,
+    ) -> Result<String> {
+        unsafe {
+            let mut buffer: [c_char; NVML_VGPU_NAME_BUFFER_SIZE as usize] =
+                [0; NVML_VGPU_NAME_BUFFER_SIZE as usize];
+            let mut size: c_uint = NVML_VGPU_NAME_BUFFER_SIZE;
+            let result = f(
+                &*self.backend,
+                vgpu_type,
+                buffer.as_mut_ptr(),
+                &mut size as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, function));
+            }
+            Ok(CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+
+    /// Returns the PCI device id and subsystem id of `vgpu_type`.
+    pub fn get_vgpu_type_device_id(&self, vgpu_type: u32) -> Result<(u64, u64)> {
+        unsafe {
+            let mut device_id: c_ulonglong = 0;
+            let mut subsystem_id: c_ulonglong = 0;
+            let result = self.backend.nvmlVgpuTypeGetDeviceID(
+                vgpu_type,
+                &mut device_id as *mut c_ulonglong,
+                &mut subsystem_id as *mut c_ulonglong,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetDeviceID"));
+            }
+            Ok((device_id, subsystem_id))
+        }
+    }
+
+    /// Framebuffer size of `vgpu_type` in bytes.
+    pub fn get_vgpu_type_framebuffer_size(&self, vgpu_type: u32) -> Result<u64> {
+        unsafe {
+            let mut size: c_ulonglong = 0;
+            let result = self
+                .backend
+                .nvmlVgpuTypeGetFramebufferSize(vgpu_type, &mut size as *mut c_ulonglong);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetFramebufferSize"));
+            }
+            Ok(size)
+        }
+    }
+
+    pub fn get_vgpu_type_num_display_heads(&self, vgpu_type: u32) -> Result<u32> {
+        unsafe {
+            let mut heads: c_uint = 0;
+            let result = self
+                .backend
+                .nvmlVgpuTypeGetNumDisplayHeads(vgpu_type, &mut heads as *mut c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetNumDisplayHeads"));
+            }
+            Ok(heads)
+        }
+    }
+
+    /// The maximum resolution of display head `display` of `vgpu_type`.
+    pub fn get_vgpu_type_resolution(&self, vgpu_type: u32, display: u32) -> Result<(u32, u32)> {
+        unsafe {
+            let mut x: c_uint = 0;
+            let mut y: c_uint = 0;
+            let result = self.backend.nvmlVgpuTypeGetResolution(
+                vgpu_type,
+                display as c_uint,
+                &mut x as *mut c_uint,
+                &mut y as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetResolution"));
+            }
+            Ok((x, y))
+        }
+    }
+
+    pub fn get_vgpu_type_license(&self, vgpu_type: u32) -> Result<String> {
+        unsafe {
+            let mut license: [c_char; NVML_GRID_LICENSE_BUFFER_SIZE as usize] =
+                [0; NVML_GRID_LICENSE_BUFFER_SIZE as usize];
+            let result = self.backend.nvmlVgpuTypeGetLicense(
+                vgpu_type,
+                license.as_mut_ptr(),
+                NVML_GRID_LICENSE_BUFFER_SIZE,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetLicense"));
+            }
+            Ok(CStr::from_ptr(license.as_ptr())
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+
+    /// Frame rate limit of `vgpu_type`; not supported if the limiter is disabled.
+    pub fn get_vgpu_type_frame_rate_limit(&self, vgpu_type: u32) -> Result<u32> {
+        unsafe {
+            let mut limit: c_uint = 0;
+            let result = self
+                .backend
+                .nvmlVgpuTypeGetFrameRateLimit(vgpu_type, &mut limit as *mut c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetFrameRateLimit"));
+            }
+            Ok(limit)
+        }
+    }
+
+    /// How many instances of `vgpu_type` fit on this device when it is empty.
+    pub fn get_vgpu_type_max_instances(&self, vgpu_type: u32) -> Result<u32> {
+        unsafe {
+            let mut count: c_uint = 0;
+            let result = self.backend.nvmlVgpuTypeGetMaxInstances(
+                self.dev,
+                vgpu_type,
+                &mut count as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetMaxInstances"));
+            }
+            Ok(count)
+        }
+    }
+
+    pub fn get_vgpu_type_max_instances_per_vm(&self, vgpu_type: u32) -> Result<u32> {
+        unsafe {
+            let mut count: c_uint = 0;
+            let result = self
+                .backend
+                .nvmlVgpuTypeGetMaxInstancesPerVm(vgpu_type, &mut count as *mut c_uint);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.error(result, "nvmlVgpuTypeGetMaxInstancesPerVm"));
+            }
+            Ok(count)
+        }
+    }
+
+    fn get_vgpu_type(&self, id: u32, creatable: bool) -> Result<VgpuType> {
+        let (device_id, subsystem_id) = self.get_vgpu_type_device_id(id)?;
+        let resolutions = (0..self.get_vgpu_type_num_display_heads(id)?)
+            .map(|display| self.get_vgpu_type_resolution(id, display))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(VgpuType {
+            id,
+            class: self.get_vgpu_type_class(id)?,
+            name: self.get_vgpu_type_name(id)?,
+            device_id,
+            subsystem_id,
+            framebuffer_size: self.get_vgpu_type_framebuffer_size(id)?,
+            resolutions,
+            license: self.get_vgpu_type_license(id)?,
+            frame_rate_limit: not_supported_as_none(self.get_vgpu_type_frame_rate_limit(id))?,
+            max_instances: self.get_vgpu_type_max_instances(id)?,
+            max_instances_per_vm: not_supported_as_none(
+                self.get_vgpu_type_max_instances_per_vm(id),
+            )?,
+            creatable,
+        })
+    }
+}
+
+impl Device<'_> {
+    /// The vGPU types the device supports, in the order NVML lists them; empty on
+    /// hosts without vGPU support.
+    pub fn vgpu_types(&self) -> Result<Vec<VgpuType>> {
+        let supported = match not_supported_as_none(self.handler.get_supported_vgpus())? {
+            Some(supported) => supported,
+            None => return Ok(vec![]),
+        };
+        let creatable = self.handler.get_creatable_vgpus()?;
+        supported
+            .into_iter()
+            .map(|id| self.handler.get_vgpu_type(id, creatable.contains(&id)))
+            .collect()
+    }
+}
+
+/// How a VM is identified in [`VmId`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VmIdType {
+    /// The hypervisor's domain id.
+    DomainId,
+    Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VmId {
+    pub id: String,
+    pub id_type: VmIdType,
+}
+
+/// Utilization of one vGPU instance over one sample period, in percent.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VgpuUtilizationSample {
+    pub vgpu_instance: u32,
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub sm: SampleValue,
+    pub memory: SampleValue,
+    pub encoder: SampleValue,
+    pub decoder: SampleValue,
+}
+
+/// Utilization of one process of a guest VM over one sample period, in percent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VgpuProcessUtilizationSample {
+    pub vgpu_instance: u32,
+    /// The pid inside the guest.
+    pub pid: u32,
+    pub process_name: String,
+    /// CPU timestamp in microseconds.
+    pub timestamp: u64,
+    pub sm: u32,
+    pub memory: u32,
+    pub encoder: u32,
+    pub decoder: u32,
+}
+
+/// A vGPU running on the device, as seen from the host.
+#[derive(Clone)]
+pub struct VgpuInstance<'nvml> {
+    pub handle: nvmlVgpuInstance_t,
+    // The physical device, for the backend and error context.
+    pub(crate) handler: Handler<'nvml>,
+}
+
+impl VgpuInstance<'_> {
+    /// The VM the instance is attached to.
+    pub fn vm_id(&self) -> Result<VmId> {
+        unsafe {
+            let mut id: [c_char; NVML_DEVICE_UUID_BUFFER_SIZE as usize] =
+                [0; NVML_DEVICE_UUID_BUFFER_SIZE as usize];
+            let mut id_type: nvmlVgpuVmIdType_t = nvmlVgpuVmIdType_NVML_VGPU_VM_ID_DOMAIN_ID;
+            let result = self.backend().nvmlVgpuInstanceGetVmID(
+                self.handle,
+                id.as_mut_ptr(),
+                NVML_DEVICE_UUID_BUFFER_SIZE,
+                &mut id_type as *mut nvmlVgpuVmIdType_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlVgpuInstanceGetVmID"));
+            }
+            Ok(VmId {
+                id: CStr::from_ptr(id.as_ptr()).to_string_lossy().into_owned(),
+                id_type: match id_type {
+                    nvmlVgpuVmIdType_NVML_VGPU_VM_ID_UUID => VmIdType::Uuid,
+                    _ => VmIdType::DomainId,
+                },
+            })
+        }
+    }
+
+    pub fn uuid(&self) -> Result<String> {
+        self.get_string(
+            <dyn Backend>::nvmlVgpuInstanceGetUUID,
+            NVML_DEVICE_UUID_BUFFER_SIZE,
+            "nvmlVgpuInstanceGetUUID",
+        )
+    }
+
+    /// The driver version of the guest; not available until the guest driver has
+    /// loaded.
+    pub fn vm_driver_version(&self) -> Result<String> {
+        self.get_string(
+            <dyn Backend>::nvmlVgpuInstanceGetVmDriverVersion,
+            NVML_SYSTEM_DRIVER_VERSION_BUFFER_SIZE,
+            "nvmlVgpuInstanceGetVmDriverVersion",
+        )
+    }
+
+    /// Framebuffer in use by the guest, in bytes.
+    pub fn fb_usage(&self) -> Result<u64> {
+        unsafe {
+            let mut usage: c_ulonglong = 0;
+            let result = self
+                .backend()
+                .nvmlVgpuInstanceGetFbUsage(self.handle, &mut usage as *mut c_ulonglong);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlVgpuInstanceGetFbUsage"));
+            }
+            Ok(usage)
+        }
+    }
+
+    /// Whether the guest holds a license for the vGPU.
+    pub fn license_status(&self) -> Result<bool> {
+        self.get_uint(
+            <dyn Backend>::nvmlVgpuInstanceGetLicenseStatus,
+            "nvmlVgpuInstanceGetLicenseStatus",
+        )
+        .map(|licensed| licensed != 0)
+    }
+
+    /// The id of the [`VgpuType`] of the instance.
+    pub fn vgpu_type(&self) -> Result<u32> {
+        self.get_uint(
+            <dyn Backend>::nvmlVgpuInstanceGetType,
+            "nvmlVgpuInstanceGetType",
+        )
+    }
+
+    /// Frame rate limit in frames per second; not supported if the limiter is
+    /// disabled.
+    pub fn frame_rate_limit(&self) -> Result<u32> {
+        self.get_uint(
+            <dyn Backend>::nvmlVgpuInstanceGetFrameRateLimit,
+            "nvmlVgpuInstanceGetFrameRateLimit",
+        )
+    }
+
+    /// The share of the encoder capacity the instance may use, in percent.
+    pub fn encoder_capacity(&self) -> Result<u32> {
+        self.get_uint(
+            <dyn Backend>::nvmlVgpuInstanceGetEncoderCapacity,
+            "nvmlVgpuInstanceGetEncoderCapacity",
+        )
+    }
+
+    pub fn encoder_stats(&self) -> Result<EncoderStats> {
+        unsafe {
+            let mut session_count: c_uint = 0;
+            let mut average_fps: c_uint = 0;
+            let mut average_latency: c_uint = 0;
+            let result = self.backend().nvmlVgpuInstanceGetEncoderStats(
+                self.handle,
+                &mut session_count as *mut c_uint,
+                &mut average_fps as *mut c_uint,
+                &mut average_latency as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlVgpuInstanceGetEncoderStats"));
+            }
+            Ok(EncoderStats {
+                session_count,
+                average_fps,
+                average_latency: Duration::from_micros(average_latency as u64),
+            })
+        }
+    }
+
+    pub fn encoder_sessions(&self) -> Result<Vec<EncoderSession>> {
+        let sessions = unsafe {
+            query_list(|count, sessions| {
+                self.backend()
+                    .nvmlVgpuInstanceGetEncoderSessions(self.handle, count, sessions)
+            })
+        }
+        .map_err(|result| {
+            self.handler
+                .error(result, "nvmlVgpuInstanceGetEncoderSessions")
+        })?;
+        Ok(sessions.iter().map(EncoderSession::from).collect())
+    }
+
+    pub fn fbc_stats(&self) -> Result<FbcStats> {
+        unsafe {
+            let mut stats: nvmlFBCStats_t = std::mem::zeroed();
+            let result = self
+                .backend()
+                .nvmlVgpuInstanceGetFBCStats(self.handle, &mut stats as *mut nvmlFBCStats_t);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, "nvmlVgpuInstanceGetFBCStats"));
+            }
+            Ok(FbcStats::from(&stats))
+        }
+    }
+
+    pub fn fbc_sessions(&self) -> Result<Vec<FbcSession>> {
+        let sessions = unsafe {
+            query_list(|count, sessions| {
+                self.backend()
+                    .nvmlVgpuInstanceGetFBCSessions(self.handle, count, sessions)
+            })
+        }
+        .map_err(|result| self.handler.error(result, "nvmlVgpuInstanceGetFBCSessions"))?;
+        Ok(sessions.iter().map(FbcSession::from).collect())
+    }
+
+    pub fn accounting_mode(&self) -> Result<bool> {
+        unsafe {
+            let mut mode: nvmlEnableState_t = nvmlEnableState_enum_NVML_FEATURE_DISABLED;
+            let result = self
+                .backend()
+                .nvmlVgpuInstanceGetAccountingMode(self.handle, &mut mode as *mut _);
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlVgpuInstanceGetAccountingMode"));
+            }
+            Ok(mode == nvmlEnableState_enum_NVML_FEATURE_ENABLED)
+        }
+    }
+
+    /// The guest processes with accounting statistics.
+    pub fn accounting_pids(&self) -> Result<Vec<u32>> {
+        unsafe {
+            query_list(|count, pids| {
+                self.backend()
+                    .nvmlVgpuInstanceGetAccountingPids(self.handle, count, pids)
+            })
+        }
+        .map_err(|result| {
+            self.handler
+                .error(result, "nvmlVgpuInstanceGetAccountingPids")
+        })
+    }
+
+    pub fn accounting_stats(&self, pid: u32) -> Result<AccountingStats> {
+        unsafe {
+            let mut stats: nvmlAccountingStats_t = std::mem::zeroed();
+            let result = self.backend().nvmlVgpuInstanceGetAccountingStats(
+                self.handle,
+                pid as c_uint,
+                &mut stats as *mut nvmlAccountingStats_t,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self
+                    .handler
+                    .error(result, "nvmlVgpuInstanceGetAccountingStats"));
+            }
+            Ok(AccountingStats::new(pid, &stats))
+        }
+    }
+
+    fn backend(&self) -> &dyn Backend {
+        &*self.handler.backend
+    }
+
+    fn get_uint(&self, f: VgpuInstanceUintFn, function: &'static str) -> Result<u32> {
+        unsafe {
+            let mut value: c_uint = 0;
+            let result = f(
+                &*self.handler.backend,
+                self.handle,
+                &mut value as *mut c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, function));
+            }
+            Ok(value)
+        }
+    }
+
+    fn get_string(
+        &self,
+        f: VgpuInstanceStringFn,
+        size: u32,
+        function: &'static str,
+    ) -> Result<String> {
+        unsafe {
+            let mut buffer: Vec<c_char> = vec![0; size as usize];
+            let result = f(
+                &*self.handler.backend,
+                self.handle,
+                buffer.as_mut_ptr(),
+                size as c_uint,
+            );
+            if result != nvmlReturn_enum_NVML_SUCCESS {
+                return Err(self.handler.error(result, function));
+            }
+            Ok(CStr::from_ptr(buffer.as_ptr())
+                .to_string_lossy()
+                .into_owned())
+        }
+    }
+}
+
+impl std::fmt::Debug for VgpuInstance<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VgpuInstance")
+            .field("handle", &self.handle)
+            .field("device_index", &self.handler.index())
+            .finish()
+    }
+}
+
+type VgpuInstanceUintFn =
+    unsafe fn(&(dyn Backend + 'static), nvmlVgpuInstance_t, *mut c_uint) -> nvmlReturn_t;
+
+type VgpuInstanceStringFn =
+    unsafe fn(&(dyn Backend + 'static), nvmlVgpuInstance_t, *mut c_char, c_uint) -> nvmlReturn_t;
+
+impl Handler<'_> {
+    /// The handles of the vGPU instances running on the device.
+    pub fn get_active_vgpus(&self) -> Result<Vec<nvmlVgpuInstance_t>> {
+        unsafe {
+            query_list(|count, instances| {
+                self.backend
+                    .nvmlDeviceGetActiveVgpus(self.dev, count, instances)
+            })
+            .map_err(|result| self.error(result, "nvmlDeviceGetActiveVgpus"))
+        }
+    }
+
+    /// Returns the utilization samples of every vGPU instance newer than
+    /// `last_seen_timestamp`, oldest first. Pass 0 to get the whole buffer.
+    pub fn get_vgpu_utilization(
+        &self,
+        last_seen_timestamp: u64,
+    ) -> Result<Vec<VgpuUtilizationSample>> {
+        let mut value_type: nvmlValueType_t = 0;
+        let samples = unsafe {
+            query_samples(|count, samples| {
+                self.backend.nvmlDeviceGetVgpuUtilization(
+                    self.dev,
+                    last_seen_timestamp as c_ulonglong,
+                    &mut value_type as *mut nvmlValueType_t,
+                    count,
+                    samples,
+                )
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetVgpuUtilization"))?;
+        let mut decoded = Vec::with_capacity(samples.len());
+        for sample in samples.iter() {
+            let decode = |value: &nvmlValue_t| {
+                self.decode_sample_value(value_type, value, "nvmlDeviceGetVgpuUtilization")
+            };
+            decoded.push(VgpuUtilizationSample {
+                vgpu_instance: sample.vgpuInstance,
+                timestamp: sample.timeStamp,
+                sm: decode(&sample.smUtil)?,
+                memory: decode(&sample.memUtil)?,
+                encoder: decode(&sample.encUtil)?,
+                decoder: decode(&sample.decUtil)?,
+            });
+        }
+        decoded.sort_by_key(|sample| sample.timestamp);
+        Ok(decoded)
+    }
+
+    /// Returns the utilization samples of the processes in every guest newer than
+    /// `last_seen_timestamp`, oldest first. Pass 0 to get the whole buffer.
+    pub fn get_vgpu_process_utilization(
+        &self,
+        last_seen_timestamp: u64,
+    ) -> Result<Vec<VgpuProcessUtilizationSample>> {
+        let samples: Vec<nvmlVgpuProcessUtilizationSample_t> = unsafe {
+            query_samples(|count, samples| {
+                self.backend.nvmlDeviceGetVgpuProcessUtilization(
+                    self.dev,
+                    last_seen_timestamp as c_ulonglong,
+                    count,
+                    samples,
+                )
+            })
+        }
+        .map_err(|result| self.error(result, "nvmlDeviceGetVgpuProcessUtilization"))?;
+        let mut samples: Vec<VgpuProcessUtilizationSample> = samples
+            .iter()
+            .map(|sample| VgpuProcessUtilizationSample {
+                vgpu_instance: sample.vgpuInstance,
+                pid: sample.pid,
+                process_name: unsafe { CStr::from_ptr(sample.processName.as_ptr()) }
+                    .to_string_lossy()
+                    .into_owned(),
+                timestamp: sample.timeStamp,
+                sm: sample.smUtil,
+                memory: sample.memUtil,
+                encoder: sample.encUtil,
+                decoder: sample.decUtil,
+            })
+            .collect();
+        samples.sort_by_key(|sample| sample.timestamp);
+        Ok(samples)
+    }
+}
+
+impl<'nvml> Device<'nvml> {
+    /// The vGPU instances running on the device; empty on hosts without vGPU
+    /// support.
+    pub fn vgpu_instances(&self) -> Result<Vec<VgpuInstance<'nvml>>> {
+        let handles = not_supported_as_none(self.handler.get_active_vgpus())?;
+        Ok(handles
+            .unwrap_or_default()
+            .into_iter()
+            .map(|handle| VgpuInstance {
+                handle,
+                handler: self.handler.clone(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::simulated::{
+        SimulatedBackend, SimulatedDevice, SimulatedEncoderSession, SimulatedProcess,
+        SimulatedProcessUtilization, SimulatedVgpuInstance, SimulatedVgpuType,
+        SimulatedVgpuUtilization,
+    };
+    use crate::NVML;
+    use std::sync::Arc;
+
+    #[test]
+    fn vgpu_types_are_listed_with_their_properties() {
+        let mut device = SimulatedDevice::new(0);
+        device.vgpu_types = vec![
+            SimulatedVgpuType::new(11, "GRID V100-4Q"),
+            SimulatedVgpuType {
+                class: "NVS".to_owned(),
+                resolutions: vec![(1280, 1024)],
+                frame_rate_limit: None,
+                ..SimulatedVgpuType::new(12, "GRID V100-1B")
+            },
+        ];
+        device.creatable_vgpu_types = vec![12];
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(vec![
+            device,
+            SimulatedDevice::new(1),
+        ])))
+        .unwrap();
+
+        let types = Device::new(&nvml, 0).unwrap().vgpu_types().unwrap();
+        assert_eq!(types.len(), 2);
+        assert_eq!(types[0].name, "GRID V100-4Q");
+        assert_eq!(types[0].class, "Quadro");
+        assert_eq!(types[0].framebuffer_size, 4 << 30);
+        assert_eq!(types[0].resolutions, vec![(4096, 2160); 4]);
+        assert_eq!(types[0].frame_rate_limit, Some(60));
+        assert_eq!(types[0].max_instances, 4);
+        assert_eq!(types[0].max_instances_per_vm, Some(1));
+        assert!(!types[0].creatable);
+        assert_eq!(types[1].id, 12);
+        assert_eq!(types[1].class, "NVS");
+        assert_eq!(types[1].resolutions, vec![(1280, 1024)]);
+        assert_eq!(types[1].frame_rate_limit, None);
+        assert!(types[1].creatable);
+
+        assert!(Device::new(&nvml, 1)
+            .unwrap()
+            .vgpu_types()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn vgpu_instances_report_their_guest() {
+        use crate::encoder::EncoderType;
+
+        let mut device = SimulatedDevice::new(0);
+        device.vgpu_types = vec![SimulatedVgpuType::new(11, "GRID V100-4Q")];
+        let mut instance = SimulatedVgpuInstance::new(7, 11);
+        instance.vm_id_type = nvmlVgpuVmIdType_NVML_VGPU_VM_ID_UUID;
+        instance.vm_id = "a8d9b3a0-0000-0000-0000-000000000000".to_owned();
+        instance.fb_usage = 1 << 30;
+        instance.encoder_sessions = vec![
+            SimulatedEncoderSession {
+                session_id: 1,
+                pid: 300,
+                average_fps: 30,
+                average_latency: 1000,
+                ..Default::default()
+            },
+            SimulatedEncoderSession {
+                session_id: 2,
+                pid: 300,
+                codec: nvmlEncoderQueryType_enum_NVML_ENCODER_QUERY_HEVC,
+                average_fps: 60,
+                average_latency: 3000,
+                ..Default::default()
+            },
+        ];
+        instance.processes = vec![SimulatedProcess {
+            pid: 300,
+            name: "obs".to_owned(),
+            used_gpu_memory: 0,
+        }];
+        instance.utilization = vec![
+            SimulatedVgpuUtilization {
+                timestamp: 20,
+                sm: 40,
+                ..Default::default()
+            },
+            SimulatedVgpuUtilization {
+                timestamp: 10,
+                sm: 20,
+                ..Default::default()
+            },
+        ];
+        instance.process_utilization = vec![SimulatedProcessUtilization {
+            pid: 300,
+            timestamp: 15,
+            encoder: 25,
+            ..Default::default()
+        }];
+        device.vgpu_instances = vec![instance];
+        let nvml = NVML::with_backend(Arc::new(SimulatedBackend::new(vec![
+            device,
+            SimulatedDevice::new(1),
+        ])))
+        .unwrap();
+        let device = Device::new(&nvml, 0).unwrap();
+
+        let instances = device.vgpu_instances().unwrap();
+        assert_eq!(instances.len(), 1);
+        let instance = &instances[0];
+        assert_eq!(instance.handle, 7);
+        let vm_id = instance.vm_id().unwrap();
+        assert_eq!(vm_id.id_type, VmIdType::Uuid);
+        assert_eq!(vm_id.id, "a8d9b3a0-0000-0000-0000-000000000000");
+        assert_eq!(instance.vgpu_type().unwrap(), 11);
+        assert_eq!(instance.fb_usage().unwrap(), 1 << 30);
+        assert!(instance.license_status().unwrap());
+        assert_eq!(instance.vm_driver_version().unwrap(), "440.33.01");
+
+        let stats = instance.encoder_stats().unwrap();
+        assert_eq!(stats.session_count, 2);
+        assert_eq!(stats.average_fps, 45);
+        assert_eq!(stats.average_latency, Duration::from_millis(2));
+        let sessions = instance.encoder_sessions().unwrap();
+        assert_eq!(sessions[1].codec, Some(EncoderType::Hevc));
+        assert_eq!(sessions[1].vgpu_instance, Some(7));
+        assert!(instance.fbc_sessions().unwrap().is_empty());
+        assert_eq!(instance.fbc_stats().unwrap().session_count, 0);
+        assert_eq!(
+            instance.accounting_pids().unwrap_err().kind(),
+            ErrorKind::NotSupported
+        );
+
+        let utilization = device.handler.get_vgpu_utilization(0).unwrap();
+        let timestamps: Vec<u64> = utilization.iter().map(|u| u.timestamp).collect();
+        assert_eq!(timestamps, vec![10, 20]);
+        assert_eq!(utilization[1].vgpu_instance, 7);
+        assert!(device.handler.get_vgpu_utilization(20).unwrap().is_empty());
+        let processes = device.handler.get_vgpu_process_utilization(0).unwrap();
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].process_name, "obs");
+        assert_eq!(processes[0].encoder, 25);
+
+        assert!(Device::new(&nvml, 1)
+            .unwrap()
+            .vgpu_instances()
+            .unwrap()
+            .is_empty());
+    }
+}